@@ -0,0 +1,622 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    export::{ExportOptions, Latex},
+    matrix::Matrix,
+    scalar::{Ring, Scalar, Semiring},
+};
+
+// Arbitrary-precision integer, stored as a sign and base 2^32 digits with the least significant first
+// The digits never have trailing zeros, and zero is never negative
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+// *> Magnitude
+
+fn trim(magnitude: &mut Vec<u32>) {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+}
+
+fn compare_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0_u64;
+    for (index, digit) in long.iter().enumerate() {
+        let sum = *digit as u64 + *short.get(index).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+// Expects a >= b
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0_i64;
+    for (index, digit) in a.iter().enumerate() {
+        let mut difference = *digit as i64 - *b.get(index).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if difference < 0 {
+            difference += 1 << 32;
+            borrow = 1;
+        }
+        result.push(difference as u32);
+    }
+    trim(&mut result);
+    result
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0_u32; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0_u64;
+        for (j, y) in b.iter().enumerate() {
+            let product = *x as u64 * *y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    trim(&mut result);
+    result
+}
+
+fn shift_left(a: &[u32], bits: u32) -> Vec<u32> {
+    if bits == 0 {
+        return a.to_vec();
+    }
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0_u32;
+    for digit in a {
+        result.push((digit << bits) | carry);
+        carry = digit >> (32 - bits);
+    }
+    result.push(carry);
+    result
+}
+
+fn shift_right(a: &[u32], bits: u32) -> Vec<u32> {
+    if bits == 0 {
+        return a.to_vec();
+    }
+    let mut result = vec![0_u32; a.len()];
+    for index in 0..a.len() {
+        let high = a.get(index + 1).map_or(0, |digit| digit << (32 - bits));
+        result[index] = (a[index] >> bits) | high;
+    }
+    result
+}
+
+// Division by a single digit, returns the quotient and the remainder
+fn div_rem_digit(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0_u32; a.len()];
+    let mut remainder = 0_u64;
+    for index in (0..a.len()).rev() {
+        let current = (remainder << 32) | a[index] as u64;
+        quotient[index] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    trim(&mut quotient);
+    (quotient, remainder as u32)
+}
+
+// Long division of the magnitudes, the divisor must not be zero
+// @see Knuth, The Art of Computer Programming, Vol. 2, 4.3.1, Algorithm D
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitude(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    if b.len() == 1 {
+        let (quotient, remainder) = div_rem_digit(a, b[0]);
+        let mut remainder = vec![remainder];
+        trim(&mut remainder);
+        return (quotient, remainder);
+    }
+
+    // Normalize so that the most significant digit of the divisor has its high bit set
+    let shift = b[b.len() - 1].leading_zeros();
+    let divisor = shift_left(b, shift);
+    let divisor = &divisor[..b.len()];
+    let mut remainder = shift_left(a, shift);
+    if remainder.len() == a.len() {
+        remainder.push(0);
+    }
+
+    let n = divisor.len();
+    let m = remainder.len() - n - 1;
+    let base = 1_u64 << 32;
+    let mut quotient = vec![0_u32; m + 1];
+    for j in (0..=m).rev() {
+        let numerator = ((remainder[j + n] as u64) << 32) | remainder[j + n - 1] as u64;
+        let mut estimate = numerator / divisor[n - 1] as u64;
+        let mut rest = numerator % divisor[n - 1] as u64;
+        while estimate >= base
+            || estimate * divisor[n - 2] as u64 > ((rest << 32) | remainder[j + n - 2] as u64)
+        {
+            estimate -= 1;
+            rest += divisor[n - 1] as u64;
+            if rest >= base {
+                break;
+            }
+        }
+
+        // Multiply and subtract
+        let mut borrow = 0_i64;
+        let mut carry = 0_u64;
+        for i in 0..n {
+            let product = estimate * divisor[i] as u64 + carry;
+            carry = product >> 32;
+            let difference = remainder[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+            remainder[i + j] = difference as u32;
+            borrow = if difference < 0 { 1 } else { 0 };
+        }
+        let difference = remainder[j + n] as i64 - borrow - carry as i64;
+        remainder[j + n] = difference as u32;
+
+        // The estimate was one too large, add the divisor back
+        if difference < 0 {
+            estimate -= 1;
+            let mut carry = 0_u64;
+            for i in 0..n {
+                let sum = remainder[i + j] as u64 + divisor[i] as u64 + carry;
+                remainder[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            remainder[j + n] = remainder[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = estimate as u32;
+    }
+
+    trim(&mut quotient);
+    let mut remainder = shift_right(&remainder[..n], shift);
+    trim(&mut remainder);
+    (quotient, remainder)
+}
+
+// *< Magnitude
+
+impl BigInt {
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> BigInt {
+        trim(&mut magnitude);
+        BigInt {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from_parts(false, self.magnitude.clone())
+    }
+
+    // -1, 0 or 1
+    pub fn signum(&self) -> i32 {
+        if self.magnitude.is_empty() {
+            0
+        } else if self.negative {
+            -1
+        } else {
+            1
+        }
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> BigInt {
+        let mut base = self.clone();
+        let mut result = BigInt::from(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    // Quotient rounded toward zero and remainder with the sign of the dividend, as the primitive integers
+    // Panics if the divisor is zero
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        if divisor.magnitude.is_empty() {
+            panic!("BigInt division by zero");
+        }
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &divisor.magnitude);
+        (
+            BigInt::from_parts(self.negative != divisor.negative, quotient),
+            BigInt::from_parts(self.negative, remainder),
+        )
+    }
+
+    // Quotient such that the remainder is always positive, as `i64::div_euclid`
+    pub fn div_euclid(&self, divisor: &BigInt) -> BigInt {
+        let (quotient, remainder) = self.div_rem(divisor);
+        if remainder.negative {
+            if divisor.negative {
+                quotient + BigInt::from(1)
+            } else {
+                quotient - BigInt::from(1)
+            }
+        } else {
+            quotient
+        }
+    }
+
+    // Remainder in [0, |divisor|), as `i64::rem_euclid`
+    pub fn rem_euclid(&self, divisor: &BigInt) -> BigInt {
+        let (_, remainder) = self.div_rem(divisor);
+        if remainder.negative {
+            &remainder + &divisor.abs()
+        } else {
+            remainder
+        }
+    }
+
+    // Greatest common divisor, always positive or zero
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let [mut a, mut b] = [self.abs(), other.abs()];
+        while !b.magnitude.is_empty() {
+            let (_, remainder) = a.div_rem(&b);
+            [a, b] = [b, remainder];
+        }
+        a
+    }
+
+    // Returns (g, x, y) with g = gcd(self, other) = x * self + y * other
+    pub fn extended_gcd(&self, other: &BigInt) -> (BigInt, BigInt, BigInt) {
+        let [mut r0, mut r1] = [self.clone(), other.clone()];
+        let [mut s0, mut s1] = [BigInt::from(1), BigInt::from(0)];
+        let [mut t0, mut t1] = [BigInt::from(0), BigInt::from(1)];
+        while !r1.magnitude.is_empty() {
+            let (quotient, remainder) = r0.div_rem(&r1);
+            let s2 = &s0 - &(&quotient * &s1);
+            let t2 = &t0 - &(&quotient * &t1);
+            [r0, r1] = [r1, remainder];
+            [s0, s1] = [s1, s2];
+            [t0, t1] = [t1, t2];
+        }
+        if r0.negative {
+            (-r0, -s0, -t0)
+        } else {
+            (r0, s0, t0)
+        }
+    }
+
+    // None if the value doesn't fit
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0_u128, |value, digit| (value << 32) | *digit as u128);
+        if self.negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    // Closest floating point value, infinite if it's too large
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0., |value, digit| value * 4294967296. + *digit as f64);
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+// * Operations
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitude(&self.magnitude, &rhs.magnitude),
+            );
+        }
+        match compare_magnitude(&self.magnitude, &rhs.magnitude) {
+            Ordering::Less => {
+                BigInt::from_parts(rhs.negative, sub_magnitude(&rhs.magnitude, &self.magnitude))
+            }
+            _ => BigInt::from_parts(
+                self.negative,
+                sub_magnitude(&self.magnitude, &rhs.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &-rhs.clone()
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            mul_magnitude(&self.magnitude, &rhs.magnitude),
+        )
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+// Rounded toward zero, as the primitive integers
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Div for BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Rem for BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Decimal representation
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.magnitude.is_empty() {
+            return write!(f, "0");
+        }
+        // Groups of 9 digits, the least significant first
+        let mut groups = vec![];
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = div_rem_digit(&magnitude, 1_000_000_000);
+            groups.push(remainder);
+            magnitude = quotient;
+        }
+        let mut text = String::from(if self.negative { "-" } else { "" });
+        text.push_str(&groups.pop().unwrap_or(0).to_string());
+        for group in groups.iter().rev() {
+            text.push_str(&format!("{:09}", group));
+        }
+        write!(f, "{}", text)
+    }
+}
+
+// *> From
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mut magnitude = value.unsigned_abs();
+        let mut digits = vec![];
+        while magnitude > 0 {
+            digits.push(magnitude as u32);
+            magnitude >>= 32;
+        }
+        BigInt::from_parts(value < 0, digits)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        BigInt::from(value as i128)
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(value: i32) -> Self {
+        BigInt::from(value as i128)
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        BigInt::from(value as i128)
+    }
+}
+
+// Only integer values can be converted, e.g. 1e30 but not 0.5
+impl TryFrom<f64> for BigInt {
+    type Error = String;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() || value.fract() != 0. {
+            return Err(format!("Can't convert {} to an integer", value));
+        }
+        if value.abs() < 1e38 {
+            return Ok(BigInt::from(value as i128));
+        }
+        // value = mantissa * 2^exponent with a positive exponent for large values
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as u32 - 1075;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let magnitude = shift_left(&[mantissa as u32, (mantissa >> 32) as u32], exponent % 32);
+        let mut digits = vec![0; (exponent / 32) as usize];
+        digits.extend(magnitude);
+        Ok(BigInt::from_parts(value < 0., digits))
+    }
+}
+
+// Parse an optional sign followed by decimal digits
+impl FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Invalid integer '{}'", s));
+        }
+
+        let mut magnitude = vec![];
+        for chunk in digits.as_bytes().chunks(9) {
+            let chunk = std::str::from_utf8(chunk).map_err(|e| e.to_string())?;
+            let value: u32 = chunk
+                .parse()
+                .map_err(|_| format!("Invalid integer '{}'", s))?;
+            let scale = [10_u32.pow(chunk.len() as u32)];
+            magnitude = add_magnitude(&mul_magnitude(&magnitude, &scale), &[value]);
+            trim(&mut magnitude);
+        }
+        Ok(BigInt::from_parts(negative, magnitude))
+    }
+}
+
+// *< From
+
+// * Scalar
+
+impl Scalar for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.negative
+    }
+}
+
+impl Semiring for BigInt {}
+
+impl Ring for BigInt {}
+
+// * Export
+
+impl Latex for BigInt {
+    fn to_latex(&self, _options: &ExportOptions) -> String {
+        self.to_string()
+    }
+}
+
+// * Conversions
+
+impl Matrix {
+    // Integer value of each of the elements, fails if any of them has a fractional part
+    pub fn to_integer(&self) -> Result<Matrix<BigInt>, String> {
+        let rows = self
+            .iter_rows()
+            .map(|row| row.iter().map(|value| BigInt::try_from(*value)).collect())
+            .collect::<Result<Vec<Vec<BigInt>>, String>>()?;
+        Ok(Matrix::from(rows))
+    }
+}
+
+impl Matrix<BigInt> {
+    // Closest floating point value of each of the elements
+    pub fn to_f64(&self) -> Matrix {
+        Matrix::from(
+            self.iter_rows()
+                .map(|row| row.iter().map(BigInt::to_f64).collect())
+                .collect::<Vec<Vec<f64>>>(),
+        )
+    }
+}
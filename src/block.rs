@@ -0,0 +1,147 @@
+use crate::{
+    matrix::Matrix,
+    scalar::{Scalar, Semiring},
+    vector::Vector,
+};
+
+// * Products
+
+impl<K: Semiring> Matrix<K> {
+    // Kronecker product, each element a[i][j] is replaced by the block a[i][j] * B
+    pub fn kronecker(&self, other: &Matrix<K>) -> Matrix<K> {
+        let [rows, cols] = self.shape();
+        let [other_rows, other_cols] = other.shape();
+        let mut result = Matrix::zeros([rows * other_rows, cols * other_cols]);
+        for row in 0..rows {
+            for column in 0..cols {
+                for other_row in 0..other_rows {
+                    for other_column in 0..other_cols {
+                        result[row * other_rows + other_row][column * other_cols + other_column] =
+                            self[row][column].clone() * other[other_row][other_column].clone();
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    // Element-wise product, an empty matrix is returned if the shapes are different
+    pub fn hadamard(&self, other: &Matrix<K>) -> Matrix<K> {
+        let shape = self.shape();
+        if shape != other.shape() {
+            return Matrix::zeros([0, 0]);
+        }
+
+        let mut result = Matrix::zeros(shape);
+        for row in 0..shape[0] {
+            for column in 0..shape[1] {
+                result[row][column] = self[row][column].clone() * other[row][column].clone();
+            }
+        }
+        result
+    }
+}
+
+impl<K: Semiring> Vector<K> {
+    // Outer product u v^T, a matrix of shape [u.size(), v.size()]
+    pub fn outer(&self, other: &Vector<K>) -> Matrix<K> {
+        let mut result = Matrix::zeros([self.size(), other.size()]);
+        for row in 0..self.size() {
+            for column in 0..other.size() {
+                result[row][column] = self[row].clone() * other[column].clone();
+            }
+        }
+        result
+    }
+}
+
+// * Assembly
+
+impl<K: Scalar> Matrix<K> {
+    // Place the matrices side by side, they must all have the same number of rows
+    pub fn hstack(matrices: &[&Matrix<K>]) -> Result<Matrix<K>, String> {
+        let Some(first) = matrices.first() else {
+            return Ok(Matrix::zeros([0, 0]));
+        };
+        let rows = first.shape()[0];
+        if let Some(matrix) = matrices.iter().find(|matrix| matrix.shape()[0] != rows) {
+            return Err(format!(
+                "Can't stack horizontally shapes {:?} and {:?}",
+                first.shape(),
+                matrix.shape()
+            ));
+        }
+
+        let cols = matrices.iter().map(|matrix| matrix.shape()[1]).sum();
+        let mut result = Matrix::zeros([rows, cols]);
+        let mut offset = 0;
+        for matrix in matrices {
+            let [_, width] = matrix.shape();
+            for row in 0..rows {
+                for column in 0..width {
+                    result[row][offset + column] = matrix[row][column].clone();
+                }
+            }
+            offset += width;
+        }
+        Ok(result)
+    }
+
+    // Place the matrices on top of each other, they must all have the same number of columns
+    pub fn vstack(matrices: &[&Matrix<K>]) -> Result<Matrix<K>, String> {
+        let Some(first) = matrices.first() else {
+            return Ok(Matrix::zeros([0, 0]));
+        };
+        let cols = first.shape()[1];
+        if let Some(matrix) = matrices.iter().find(|matrix| matrix.shape()[1] != cols) {
+            return Err(format!(
+                "Can't stack vertically shapes {:?} and {:?}",
+                first.shape(),
+                matrix.shape()
+            ));
+        }
+
+        let rows = matrices.iter().map(|matrix| matrix.shape()[0]).sum();
+        let mut result = Matrix::zeros([rows, cols]);
+        let mut offset = 0;
+        for matrix in matrices {
+            let [height, _] = matrix.shape();
+            for row in 0..height {
+                for column in 0..cols {
+                    result[offset + row][column] = matrix[row][column].clone();
+                }
+            }
+            offset += height;
+        }
+        Ok(result)
+    }
+
+    // Assemble a matrix from a grid of blocks, given row by row
+    // -- the blocks of a row share their number of rows, and each row of blocks has the same width
+    pub fn block(blocks: &[&[&Matrix<K>]]) -> Result<Matrix<K>, String> {
+        let rows = blocks
+            .iter()
+            .map(|row| Matrix::hstack(row))
+            .collect::<Result<Vec<Matrix<K>>, String>>()?;
+        Matrix::vstack(&rows.iter().collect::<Vec<&Matrix<K>>>())
+    }
+
+    // Block diagonal matrix, every element outside of the blocks is zero
+    pub fn block_diag(matrices: &[&Matrix<K>]) -> Matrix<K> {
+        let rows = matrices.iter().map(|matrix| matrix.shape()[0]).sum();
+        let cols = matrices.iter().map(|matrix| matrix.shape()[1]).sum();
+        let mut result = Matrix::zeros([rows, cols]);
+        let [mut row_offset, mut column_offset] = [0, 0];
+        for matrix in matrices {
+            let [height, width] = matrix.shape();
+            for row in 0..height {
+                for column in 0..width {
+                    result[row_offset + row][column_offset + column] = matrix[row][column].clone();
+                }
+            }
+            row_offset += height;
+            column_offset += width;
+        }
+        result
+    }
+}
@@ -0,0 +1,429 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, Read},
+    path::Path,
+    process::ExitCode,
+};
+
+use crate::repl;
+use matrix::{
+    export::ExportOptions,
+    io::{
+        csv::{read_csv, write_csv, CsvOptions},
+        market::{read_matrix_market, write_matrix_market, MarketFormat, MarketOptions},
+        to_vector,
+    },
+    matrix::Matrix,
+    vector::Vector,
+};
+
+const USAGE: &str = "Usage: matrix <command> [options] [arguments]
+
+Commands:
+  det [FILE]                        Determinant of a square matrix
+  inv [FILE]                        Inverse of a square matrix
+  rank [FILE]                       Rank of a matrix
+  rref [FILE]                       Reduced row echelon form of a matrix
+  transpose [FILE]                  Transpose of a matrix
+  trace [FILE]                      Trace of a square matrix
+  mul A B                           Product of the matrices A and B
+  solve A B                         Solution x of the system A x = B
+  projection FOV RATIO NEAR FAR     Perspective projection matrix
+  repl                              Interactive mode, also reads scripts from the standard input
+
+Matrices are read from the given files, or from the standard input if a file is missing or `-`.
+
+Options:
+  -i, --input <FORMAT>      Input format: text, csv or mtx (guessed from the file extension)
+  -o, --output <FORMAT>     Output format: text, csv, mtx, latex, markdown, matlab or numpy
+  -p, --precision <DIGITS>  Number of digits after the decimal point
+  -f, --fractions           Display exact values as fractions (latex, markdown, matlab, numpy)
+  -h, --help                Display this message
+
+Exit codes: 0 on success, 1 if the result can't be computed, 2 on invalid usage, 3 on invalid input";
+
+// Error of a command, each kind has its own exit code
+enum CliError {
+    // The result can't be computed, e.g. the inverse of a singular matrix
+    Compute(String),
+    // Invalid command, option or number of arguments
+    Usage(String),
+    // The input can't be read or parsed
+    Input(String),
+}
+
+impl CliError {
+    fn code(&self) -> u8 {
+        match self {
+            CliError::Compute(_) => 1,
+            CliError::Usage(_) => 2,
+            CliError::Input(_) => 3,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            CliError::Compute(message) | CliError::Usage(message) | CliError::Input(message) => {
+                message
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputFormat {
+    Text,
+    Csv,
+    Market,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Csv,
+    Market,
+    Latex,
+    Markdown,
+    Matlab,
+    Numpy,
+}
+
+struct Options {
+    input: Option<InputFormat>,
+    output: OutputFormat,
+    export: ExportOptions,
+    arguments: Vec<String>,
+}
+
+enum Output {
+    Scalar(f64),
+    Vector(Vector),
+    Matrix(Matrix),
+}
+
+// * Arguments
+
+fn parse_input_format(value: &str) -> Result<InputFormat, CliError> {
+    match value {
+        "text" | "txt" => Ok(InputFormat::Text),
+        "csv" => Ok(InputFormat::Csv),
+        "mtx" | "market" => Ok(InputFormat::Market),
+        _ => Err(CliError::Usage(format!("Unknown input format '{}'", value))),
+    }
+}
+
+fn parse_output_format(value: &str) -> Result<OutputFormat, CliError> {
+    match value {
+        "text" | "txt" => Ok(OutputFormat::Text),
+        "csv" => Ok(OutputFormat::Csv),
+        "mtx" | "market" => Ok(OutputFormat::Market),
+        "latex" | "tex" => Ok(OutputFormat::Latex),
+        "markdown" | "md" => Ok(OutputFormat::Markdown),
+        "matlab" => Ok(OutputFormat::Matlab),
+        "numpy" | "npy" => Ok(OutputFormat::Numpy),
+        _ => Err(CliError::Usage(format!(
+            "Unknown output format '{}'",
+            value
+        ))),
+    }
+}
+
+// Negative numbers are arguments and not options
+fn is_option(argument: &str) -> bool {
+    argument.len() > 1
+        && argument.starts_with('-')
+        && !argument[1..]
+            .starts_with(|character: char| character.is_ascii_digit() || character == '.')
+}
+
+fn parse_options(arguments: &[String]) -> Result<Options, CliError> {
+    let mut options = Options {
+        input: None,
+        output: OutputFormat::Text,
+        export: ExportOptions::default(),
+        arguments: vec![],
+    };
+
+    let mut iterator = arguments.iter();
+    while let Some(argument) = iterator.next() {
+        if !is_option(argument) {
+            options.arguments.push(argument.clone());
+            continue;
+        }
+
+        let (name, inline_value) = match argument.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (argument.as_str(), None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| iterator.next().cloned())
+                .ok_or_else(|| CliError::Usage(format!("Missing value for {}", name)))
+        };
+        match name {
+            "-i" | "--input" => options.input = Some(parse_input_format(&value()?)?),
+            "-o" | "--output" => options.output = parse_output_format(&value()?)?,
+            "-p" | "--precision" => {
+                let precision = value()?;
+                options.export.precision =
+                    Some(precision.parse().map_err(|_| {
+                        CliError::Usage(format!("Invalid precision '{}'", precision))
+                    })?);
+            }
+            "-f" | "--fractions" => options.export.fractions = true,
+            _ => return Err(CliError::Usage(format!("Unknown option '{}'", name))),
+        }
+    }
+
+    Ok(options)
+}
+
+// * Input
+
+fn guess_format(path: &str) -> InputFormat {
+    match Path::new(path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("csv") => InputFormat::Csv,
+        Some("mtx") => InputFormat::Market,
+        _ => InputFormat::Text,
+    }
+}
+
+fn read_matrix(source: Option<&str>, format: Option<InputFormat>) -> Result<Matrix, CliError> {
+    let input_error = |error: String| match source {
+        Some(path) if path != "-" => CliError::Input(format!("{}: {}", path, error)),
+        _ => CliError::Input(format!("<stdin>: {}", error)),
+    };
+
+    let (reader, format): (Box<dyn Read>, InputFormat) = match source {
+        Some(path) if path != "-" => {
+            let file = File::open(path).map_err(|error| input_error(error.to_string()))?;
+            (Box::new(file), format.unwrap_or_else(|| guess_format(path)))
+        }
+        _ => (Box::new(io::stdin()), format.unwrap_or(InputFormat::Text)),
+    };
+    let mut reader = BufReader::new(reader);
+
+    match format {
+        InputFormat::Text => {
+            let mut content = String::new();
+            reader
+                .read_to_string(&mut content)
+                .map_err(|error| input_error(error.to_string()))?;
+            content
+                .parse::<Matrix>()
+                .map_err(|error| input_error(error.to_string()))
+        }
+        InputFormat::Csv => {
+            read_csv(reader, &CsvOptions::default()).map_err(|error| input_error(error.to_string()))
+        }
+        InputFormat::Market => {
+            read_matrix_market(reader).map_err(|error| input_error(error.to_string()))
+        }
+    }
+}
+
+// Read the matrices of the arguments, at most one of them can be the standard input
+fn read_matrices(options: &Options, count: usize) -> Result<Vec<Matrix>, CliError> {
+    if options.arguments.len() > count {
+        return Err(CliError::Usage(format!(
+            "Expected at most {} arguments, got {}",
+            count,
+            options.arguments.len()
+        )));
+    }
+
+    let sources: Vec<Option<&str>> = (0..count)
+        .map(|index| {
+            options
+                .arguments
+                .get(index)
+                .map(|argument| argument.as_str())
+        })
+        .collect();
+    let from_stdin = sources
+        .iter()
+        .filter(|source| matches!(source, None | Some("-")))
+        .count();
+    if from_stdin > 1 {
+        return Err(CliError::Usage(
+            "Only one matrix can be read from the standard input".to_string(),
+        ));
+    }
+
+    sources
+        .into_iter()
+        .map(|source| read_matrix(source, options.input))
+        .collect()
+}
+
+fn read_single(options: &Options) -> Result<Matrix, CliError> {
+    Ok(read_matrices(options, 1)?.remove(0))
+}
+
+fn read_square(options: &Options) -> Result<Matrix, CliError> {
+    let matrix = read_single(options)?;
+    let [rows, cols] = matrix.shape();
+    if rows != cols {
+        return Err(CliError::Compute(format!(
+            "Expected a square matrix, got shape {:?}",
+            [rows, cols]
+        )));
+    }
+    Ok(matrix)
+}
+
+// * Output
+
+fn format_scalar(value: f64, options: &Options) -> String {
+    match options.export.precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{}", value),
+    }
+}
+
+fn write_output(output: &Output, options: &Options) -> Result<(), CliError> {
+    let output_error = |error: matrix::io::Error| CliError::Input(error.to_string());
+    let export = &options.export;
+
+    // * Scalars are always written as a single number
+    let matrix = match output {
+        Output::Scalar(value) => {
+            println!("{}", format_scalar(*value, options));
+            return Ok(());
+        }
+        Output::Vector(vector) => vector.reshape(),
+        Output::Matrix(matrix) => matrix.clone(),
+    };
+
+    let text = match (options.output, output) {
+        (OutputFormat::Text, Output::Vector(vector)) => match export.precision {
+            Some(precision) => format!("{:.*}", precision, vector),
+            None => format!("{}", vector),
+        },
+        (OutputFormat::Text, _) => match export.precision {
+            Some(precision) => format!("{:.*}", precision, matrix),
+            None => format!("{}", matrix),
+        },
+        (OutputFormat::Csv, _) => {
+            write_csv(io::stdout().lock(), &matrix, &CsvOptions::default())
+                .map_err(output_error)?;
+            return Ok(());
+        }
+        (OutputFormat::Market, _) => {
+            let market = MarketOptions {
+                format: MarketFormat::Array,
+                ..Default::default()
+            };
+            write_matrix_market(io::stdout().lock(), &matrix, &market).map_err(output_error)?;
+            return Ok(());
+        }
+        (OutputFormat::Latex, Output::Vector(vector)) => vector.to_latex(export),
+        (OutputFormat::Latex, _) => matrix.to_latex(export),
+        (OutputFormat::Markdown, Output::Vector(vector)) => vector.to_markdown(export),
+        (OutputFormat::Markdown, _) => matrix.to_markdown(export),
+        (OutputFormat::Matlab, Output::Vector(vector)) => vector.to_matlab(export),
+        (OutputFormat::Matlab, _) => matrix.to_matlab(export),
+        (OutputFormat::Numpy, Output::Vector(vector)) => vector.to_numpy(export),
+        (OutputFormat::Numpy, _) => matrix.to_numpy(export),
+    };
+    println!("{}", text);
+    Ok(())
+}
+
+// * Commands
+
+fn execute(command: &str, options: &Options) -> Result<Output, CliError> {
+    match command {
+        "det" => Ok(Output::Scalar(read_square(options)?.determinant())),
+        "inv" => read_square(options)?
+            .inverse()
+            .map(Output::Matrix)
+            .map_err(CliError::Compute),
+        "rank" => Ok(Output::Scalar(read_single(options)?.rank() as f64)),
+        "rref" => Ok(Output::Matrix(read_single(options)?.reduced_row_echelon())),
+        "transpose" => Ok(Output::Matrix(read_single(options)?.transpose())),
+        "trace" => Ok(Output::Scalar(read_square(options)?.trace())),
+        "mul" => {
+            let matrices = read_matrices(options, 2)?;
+            let [a, b] = [&matrices[0], &matrices[1]];
+            let result = a.mul_mat(b);
+            if result.shape() == [0, 0] && a.shape() != [0, 0] {
+                return Err(CliError::Compute(format!(
+                    "Invalid shapes {:?} and {:?}",
+                    a.shape(),
+                    b.shape()
+                )));
+            }
+            Ok(Output::Matrix(result))
+        }
+        "solve" => {
+            let matrices = read_matrices(options, 2)?;
+            let b = to_vector(&matrices[1]).map_err(|error| CliError::Input(error.to_string()))?;
+            matrices[0]
+                .solve(&b)
+                .map(Output::Vector)
+                .map_err(CliError::Compute)
+        }
+        "projection" => {
+            if options.arguments.len() != 4 {
+                return Err(CliError::Usage(
+                    "Expected the arguments FOV RATIO NEAR FAR".to_string(),
+                ));
+            }
+            let values = options
+                .arguments
+                .iter()
+                .map(|argument| {
+                    argument
+                        .parse::<f64>()
+                        .map_err(|_| CliError::Usage(format!("Invalid number '{}'", argument)))
+                })
+                .collect::<Result<Vec<f64>, CliError>>()?;
+            Ok(Output::Matrix(Matrix::projection(
+                values[0], values[1], values[2], values[3],
+            )))
+        }
+        _ => Err(CliError::Usage(format!("Unknown command '{}'", command))),
+    }
+}
+
+pub fn run(arguments: &[String]) -> ExitCode {
+    if arguments.is_empty()
+        || arguments
+            .iter()
+            .any(|argument| argument == "-h" || argument == "--help")
+    {
+        println!("{}", USAGE);
+        return if arguments.is_empty() {
+            ExitCode::from(2)
+        } else {
+            ExitCode::SUCCESS
+        };
+    }
+
+    if arguments[0] == "repl" {
+        if arguments.len() > 1 {
+            eprintln!("matrix: The repl command has no arguments");
+            return ExitCode::from(2);
+        }
+        return repl::run();
+    }
+
+    let result = parse_options(&arguments[1..])
+        .and_then(|options| execute(&arguments[0], &options).map(|output| (output, options)))
+        .and_then(|(output, options)| write_output(&output, &options));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("matrix: {}", error.message());
+            if let CliError::Usage(_) = error {
+                eprintln!("Try 'matrix --help' for more information.");
+            }
+            ExitCode::from(error.code())
+        }
+    }
+}
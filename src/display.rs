@@ -0,0 +1,117 @@
+use std::fmt;
+
+// Maximum number of rows or columns displayed before the middle ones are elided
+const MAX_DISPLAYED: usize = 10;
+// Number of rows or columns kept on each side of the ellipsis
+const EDGE_DISPLAYED: usize = 4;
+
+// Indices of the rows or columns to display, `None` marks the ellipsis
+fn visible(length: usize) -> Vec<Option<usize>> {
+    if length <= MAX_DISPLAYED {
+        return (0..length).map(Some).collect();
+    }
+    (0..EDGE_DISPLAYED)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain(((length - EDGE_DISPLAYED)..length).map(Some))
+        .collect()
+}
+
+// Format a single value with the precision of the formatter, if any
+fn format_value<T: fmt::Display>(f: &fmt::Formatter, value: &T) -> String {
+    match f.precision() {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{}", value),
+    }
+}
+
+fn pad(cell: &str, width: usize) -> String {
+    let length = cell.chars().count();
+    format!("{}{}", " ".repeat(width.saturating_sub(length)), cell)
+}
+
+// Write the rows on a single line, as `[a, b; c, d]`
+// Compact mode never elides values
+fn write_compact<T: fmt::Display>(f: &mut fmt::Formatter, rows: &[&[T]]) -> fmt::Result {
+    let formatted: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|value| pad(&format_value(f, value), f.width().unwrap_or(0)))
+                .collect::<Vec<String>>()
+                .join(", ")
+        })
+        .collect();
+    write!(f, "[{}]", formatted.join("; "))
+}
+
+// Write the rows as an aligned grid surrounded by box-drawing brackets
+// Each column is right-aligned to its widest value, or to the formatter width if larger
+fn write_pretty<T: fmt::Display>(f: &mut fmt::Formatter, rows: &[&[T]]) -> fmt::Result {
+    let columns = rows.first().map_or(0, |row| row.len());
+    // Rows of different sizes can't be aligned, they are written on a single line instead
+    if rows.iter().any(|row| row.len() != columns) {
+        return write_compact(f, rows);
+    }
+    if rows.is_empty() || columns == 0 {
+        return write!(f, "[]");
+    }
+
+    let visible_rows = visible(rows.len());
+    let visible_columns = visible(columns);
+    let cells: Vec<Vec<String>> = visible_rows
+        .iter()
+        .map(|row| {
+            visible_columns
+                .iter()
+                .map(|column| match (row, column) {
+                    (Some(row), Some(column)) => format_value(f, &rows[*row][*column]),
+                    (Some(_), None) => "…".to_string(),
+                    (None, Some(_)) => "⋮".to_string(),
+                    (None, None) => "⋱".to_string(),
+                })
+                .collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..visible_columns.len())
+        .map(|column| {
+            cells
+                .iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(f.width().unwrap_or(0))
+        })
+        .collect();
+    let lines: Vec<String> = cells
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| pad(cell, *width))
+                .collect::<Vec<String>>()
+                .join("  ")
+        })
+        .collect();
+
+    // * A single row is displayed inline
+    if lines.len() == 1 {
+        return write!(f, "[ {} ]", lines[0]);
+    }
+
+    let inner = widths.iter().sum::<usize>() + 2 * (widths.len() - 1);
+    writeln!(f, "┌ {} ┐", " ".repeat(inner))?;
+    for line in lines.iter() {
+        writeln!(f, "│ {} │", line)?;
+    }
+    write!(f, "└ {} ┘", " ".repeat(inner))
+}
+
+pub(crate) fn write_rows<T: fmt::Display>(f: &mut fmt::Formatter, rows: &[&[T]]) -> fmt::Result {
+    if f.alternate() {
+        write_compact(f, rows)
+    } else {
+        write_pretty(f, rows)
+    }
+}
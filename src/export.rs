@@ -0,0 +1,181 @@
+// Options shared by all of the exporters
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExportOptions {
+    // Number of digits after the decimal point, the shortest representation is used if `None`
+    pub precision: Option<usize>,
+    // Render values that are fractions up to rounding (e.g. 0.5 or 1/3) as fractions
+    pub fractions: bool,
+}
+
+// Largest denominator considered when looking for a fraction
+const MAX_DENOMINATOR: i64 = 1_000_000;
+
+// Find the fraction numerator/denominator equal to the value up to a relative error of f64::EPSILON, if any
+// -- the tolerance is needed for fractions such as 1/3 that floating points can't represent exactly
+// @see https://en.wikipedia.org/wiki/Continued_fraction#Best_rational_approximations
+fn as_fraction(value: f64) -> Option<(i64, i64)> {
+    if !value.is_finite() || value.abs() >= 1e15 {
+        return None;
+    }
+    if value.fract() == 0. {
+        return Some((value as i64, 1));
+    }
+
+    let sign = if value < 0. { -1 } else { 1 };
+    let target = value.abs();
+    let mut x = target;
+    let [mut h0, mut h1] = [0_i64, 1_i64];
+    let [mut k0, mut k1] = [1_i64, 0_i64];
+    for _ in 0..64 {
+        let a = x.floor() as i64;
+        let h2 = a.checked_mul(h1)?.checked_add(h0)?;
+        let k2 = a.checked_mul(k1)?.checked_add(k0)?;
+        if k2 > MAX_DENOMINATOR {
+            return None;
+        }
+        if (h2 as f64 / k2 as f64 - target).abs() <= f64::EPSILON * target {
+            return Some((sign * h2, k2));
+        }
+        [h0, h1] = [h1, h2];
+        [k0, k1] = [k1, k2];
+        let rest = x - x.floor();
+        if rest == 0. {
+            return None;
+        }
+        x = 1. / rest;
+    }
+    None
+}
+
+fn format_number(value: f64, options: &ExportOptions) -> String {
+    match options.precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{}", value),
+    }
+}
+
+// Format a value with the default syntax shared by the text formats, `3/2` for fractions
+fn format_plain(value: f64, options: &ExportOptions, infinity: &str, nan: &str) -> String {
+    if value.is_nan() {
+        return nan.to_string();
+    }
+    if value.is_infinite() {
+        let sign = if value < 0. { "-" } else { "" };
+        return format!("{}{}", sign, infinity);
+    }
+    if options.fractions {
+        if let Some((numerator, denominator)) = as_fraction(value) {
+            if denominator == 1 {
+                return numerator.to_string();
+            }
+            return format!("{}/{}", numerator, denominator);
+        }
+    }
+    format_number(value, options)
+}
+
+pub(crate) fn format_latex(value: f64, options: &ExportOptions) -> String {
+    if value.is_nan() {
+        return "\\mathrm{NaN}".to_string();
+    }
+    if value.is_infinite() {
+        let sign = if value < 0. { "-" } else { "" };
+        return format!("{}\\infty", sign);
+    }
+    if options.fractions {
+        if let Some((numerator, denominator)) = as_fraction(value) {
+            if denominator == 1 {
+                return numerator.to_string();
+            }
+            let sign = if numerator < 0 { "-" } else { "" };
+            return format!("{}\\frac{{{}}}{{{}}}", sign, numerator.abs(), denominator);
+        }
+    }
+    format_number(value, options)
+}
+
+// Scalar that can be written in LaTeX, e.g. `\frac{1}{3}` for a fraction
+pub trait Latex {
+    fn to_latex(&self, options: &ExportOptions) -> String;
+}
+
+impl Latex for f64 {
+    fn to_latex(&self, options: &ExportOptions) -> String {
+        format_latex(*self, options)
+    }
+}
+
+// LaTeX `bmatrix` environment, with rows separated by `\\` and columns by `&`
+pub(crate) fn latex<K: Latex>(rows: &[&[K]], options: &ExportOptions) -> String {
+    let lines: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = row.iter().map(|value| value.to_latex(options)).collect();
+            format!("  {}", cells.join(" & "))
+        })
+        .collect();
+    if lines.is_empty() {
+        return "\\begin{bmatrix}\n\\end{bmatrix}".to_string();
+    }
+    format!(
+        "\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}",
+        lines.join(" \\\\\n")
+    )
+}
+
+// Markdown table, the header contains the index of each column
+pub(crate) fn markdown(rows: &[&[f64]], options: &ExportOptions) -> String {
+    let columns = rows.first().map_or(0, |row| row.len());
+    if columns == 0 {
+        return String::new();
+    }
+
+    let header: Vec<String> = (0..columns).map(|column| column.to_string()).collect();
+    let mut lines = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("|{}", " ---: |".repeat(columns)),
+    ];
+    for row in rows.iter() {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|value| format_plain(*value, options, "∞", "NaN"))
+            .collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+    lines.join("\n")
+}
+
+// MATLAB literal, with columns separated by spaces and rows by `;`
+pub(crate) fn matlab(rows: &[&[f64]], options: &ExportOptions) -> String {
+    let lines: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|value| format_plain(*value, options, "Inf", "NaN"))
+                .collect();
+            cells.join(" ")
+        })
+        .collect();
+    format!("[{}]", lines.join("; "))
+}
+
+fn numpy_row(row: &[f64], options: &ExportOptions) -> String {
+    let cells: Vec<String> = row
+        .iter()
+        .map(|value| format_plain(*value, options, "np.inf", "np.nan"))
+        .collect();
+    format!("[{}]", cells.join(", "))
+}
+
+// NumPy `np.array` literal, two-dimensional for a matrix
+// -- the dtype is explicit since integer values are written without a decimal point
+pub(crate) fn numpy_matrix(rows: &[&[f64]], options: &ExportOptions) -> String {
+    let lines: Vec<String> = rows.iter().map(|row| numpy_row(row, options)).collect();
+    format!("np.array([{}], dtype=float)", lines.join(", "))
+}
+
+// NumPy `np.array` literal, one-dimensional for a vector
+pub(crate) fn numpy_vector(elements: &[f64], options: &ExportOptions) -> String {
+    format!("np.array({}, dtype=float)", numpy_row(elements, options))
+}
@@ -0,0 +1,281 @@
+use crate::{matrix::Matrix, scalar::Field, vector::Vector};
+
+// Largest number of iterations of the iterative methods before giving up
+const MAX_ITERATIONS: usize = 100;
+
+// Coefficients of the [13/13] Padé approximant of the exponential
+const PADE_13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+// Largest 1-norm for which the [13/13] Padé approximant is accurate to the double precision
+const THETA_13: f64 = 5.371920351148152;
+
+fn square_shape(matrix: &Matrix, function: &str) -> Result<usize, String> {
+    let [rows, cols] = matrix.shape();
+    if rows != cols {
+        return Err(format!(
+            "Matrix {} expects a square matrix, got shape {:?}",
+            function,
+            [rows, cols]
+        ));
+    }
+    Ok(rows)
+}
+
+// Maximum absolute column sum
+fn norm_1(matrix: &Matrix) -> f64 {
+    let [rows, cols] = matrix.shape();
+    (0..cols)
+        .map(|column| (0..rows).map(|row| matrix[row][column].abs()).sum())
+        .fold(0., f64::max)
+}
+
+fn is_finite(matrix: &Matrix) -> bool {
+    matrix.iter().all(|value| value.is_finite())
+}
+
+// Integer powers, for any field
+impl<K: Field> Matrix<K> {
+    // Exponentiation by squaring, negative powers use the inverse
+    pub fn pow(&self, exponent: i32) -> Result<Matrix<K>, String> {
+        let [rows, cols] = self.shape();
+        if rows != cols {
+            return Err(format!(
+                "Matrix power expects a square matrix, got shape {:?}",
+                [rows, cols]
+            ));
+        }
+
+        let mut base = if exponent < 0 {
+            self.inverse()?
+        } else {
+            self.clone()
+        };
+        let mut exponent = exponent.unsigned_abs();
+        let mut result = Matrix::identity(rows, K::one());
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul_mat(&base);
+            }
+            base = base.mul_mat(&base);
+            exponent >>= 1;
+        }
+        Ok(result)
+    }
+}
+
+impl Matrix {
+    // Matrix exponential, with the scaling and squaring method and a [13/13] Padé approximant
+    // @see Higham, The Scaling and Squaring Method for the Matrix Exponential Revisited (2005)
+    pub fn exp(&self) -> Result<Matrix, String> {
+        let size = square_shape(self, "exponential")?;
+        if size == 0 {
+            return Ok(Matrix::new([0, 0]));
+        }
+        if !is_finite(self) {
+            return Err("Matrix exponential expects finite values".to_string());
+        }
+
+        // * Scale the matrix so that its norm is small enough for the approximant
+        let norm = norm_1(self);
+        let squarings = if norm > THETA_13 {
+            (norm / THETA_13).log2().ceil() as i32
+        } else {
+            0
+        };
+        let a = self.clone() * 2_f64.powi(-squarings);
+
+        // * Padé approximant r(A) = (V - U)^-1 (V + U)
+        let identity = Matrix::identity(size, 1.);
+        let a2 = a.mul_mat(&a);
+        let a4 = a2.mul_mat(&a2);
+        let a6 = a4.mul_mat(&a2);
+        let b = PADE_13;
+        let odd = a6.mul_mat(&(a6.clone() * b[13] + a4.clone() * b[11] + a2.clone() * b[9]))
+            + a6.clone() * b[7]
+            + a4.clone() * b[5]
+            + a2.clone() * b[3]
+            + identity.clone() * b[1];
+        let u = a.mul_mat(&odd);
+        let v = a6.mul_mat(&(a6.clone() * b[12] + a4.clone() * b[10] + a2.clone() * b[8]))
+            + a6 * b[6]
+            + a4 * b[4]
+            + a2 * b[2]
+            + identity * b[0];
+        let numerator = v.clone() + u.clone();
+        let denominator = v - u;
+        let mut result = denominator.inverse()?.mul_mat(&numerator);
+
+        // * Undo the scaling, exp(A) = exp(A / 2^s)^(2^s)
+        for _ in 0..squarings {
+            result = result.mul_mat(&result);
+        }
+        if !is_finite(&result) {
+            return Err("Matrix exponential overflowed".to_string());
+        }
+        Ok(result)
+    }
+
+    // Principal square root, with the Denman–Beavers iteration
+    // Fails if the matrix is singular or has negative real eigenvalues
+    pub fn sqrt(&self) -> Result<Matrix, String> {
+        let size = square_shape(self, "square root")?;
+        if size == 0 {
+            return Ok(Matrix::new([0, 0]));
+        }
+
+        // Y converges to sqrt(A) and Z to sqrt(A)^-1
+        let mut y = self.clone();
+        let mut z = Matrix::identity(size, 1.);
+        for _ in 0..MAX_ITERATIONS {
+            let y_inverse = y
+                .inverse()
+                .map_err(|_| "Matrix square root doesn't exist")?;
+            let z_inverse = z
+                .inverse()
+                .map_err(|_| "Matrix square root doesn't exist")?;
+            let next = (y.clone() + z_inverse) * 0.5;
+            z = (z + y_inverse) * 0.5;
+            if !is_finite(&next) {
+                break;
+            }
+            let change = norm_1(&(next.clone() - y.clone()));
+            y = next;
+            if change <= 1e-14 * norm_1(&y) {
+                return Ok(y);
+            }
+        }
+        Err("Matrix square root didn't converge".to_string())
+    }
+
+    // Principal logarithm, with the inverse scaling and squaring method
+    // -- square roots are taken until the matrix is close to the identity,
+    // -- then log(A) = 2 atanh(Z) = 2 (Z + Z^3/3 + Z^5/5 + ...) with Z = (A - I)(A + I)^-1
+    pub fn log(&self) -> Result<Matrix, String> {
+        let size = square_shape(self, "logarithm")?;
+        if size == 0 {
+            return Ok(Matrix::new([0, 0]));
+        }
+
+        let identity = Matrix::identity(size, 1.);
+        let mut root = self.clone();
+        let mut roots = 0;
+        while norm_1(&(root.clone() - identity.clone())) > 0.1 {
+            if roots == MAX_ITERATIONS {
+                return Err("Matrix logarithm didn't converge".to_string());
+            }
+            root = root.sqrt().map_err(|_| "Matrix logarithm doesn't exist")?;
+            roots += 1;
+        }
+
+        let z = (root.clone() - identity.clone()).mul_mat(&(root + identity).inverse()?);
+        let z2 = z.mul_mat(&z);
+        let mut term = z.clone();
+        let mut series = z;
+        for k in 1..MAX_ITERATIONS {
+            term = term.mul_mat(&z2);
+            let scaled = term.clone() * (1. / (2 * k + 1) as f64);
+            if norm_1(&scaled) <= f64::EPSILON * norm_1(&series) {
+                break;
+            }
+            series = series + scaled;
+        }
+        Ok(series * 2_f64.powi(roots as i32 + 1))
+    }
+
+    // Real power of a symmetric positive-definite matrix, V diag(λ^p) V^T from its eigendecomposition
+    pub fn powf(&self, exponent: f64) -> Result<Matrix, String> {
+        square_shape(self, "power")?;
+        let (values, vectors) = self.symmetric_eigen()?;
+        if values.iter().any(|value| *value <= 0.) {
+            return Err("Matrix isn't positive definite".to_string());
+        }
+
+        let [rows, cols] = vectors.shape();
+        let mut scaled = vectors.clone();
+        for row in 0..rows {
+            for column in 0..cols {
+                scaled[row][column] *= values[column].powf(exponent);
+            }
+        }
+        Ok(scaled.mul_mat(&vectors.transpose()))
+    }
+
+    // Eigenvalues in decreasing order and the matching unit eigenvectors as columns,
+    // for a symmetric matrix with the cyclic Jacobi method
+    pub fn symmetric_eigen(&self) -> Result<(Vector, Matrix), String> {
+        let size = square_shape(self, "eigendecomposition")?;
+        let scale = norm_1(self);
+        for row in 0..size {
+            for column in 0..row {
+                if (self[row][column] - self[column][row]).abs() > 1e-10 * scale {
+                    return Err("Expected a symmetric matrix".to_string());
+                }
+            }
+        }
+
+        let mut a = self.clone();
+        let mut vectors = Matrix::identity(size, 1.);
+        for _ in 0..MAX_ITERATIONS {
+            let off_diagonal: f64 = (0..size)
+                .flat_map(|p| ((p + 1)..size).map(move |q| [p, q]))
+                .map(|[p, q]| a[p][q] * a[p][q])
+                .sum();
+            if off_diagonal <= f64::EPSILON * f64::EPSILON * scale * scale {
+                break;
+            }
+
+            for p in 0..size {
+                for q in (p + 1)..size {
+                    if a[p][q] == 0. {
+                        continue;
+                    }
+                    // Rotation that cancels a[p][q]
+                    let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                    let c = 1. / (t * t + 1.).sqrt();
+                    let s = t * c;
+                    for k in 0..size {
+                        let [kp, kq] = [a[k][p], a[k][q]];
+                        a[k][p] = c * kp - s * kq;
+                        a[k][q] = s * kp + c * kq;
+                    }
+                    for k in 0..size {
+                        let [pk, qk] = [a[p][k], a[q][k]];
+                        a[p][k] = c * pk - s * qk;
+                        a[q][k] = s * pk + c * qk;
+                    }
+                    for k in 0..size {
+                        let [kp, kq] = [vectors[k][p], vectors[k][q]];
+                        vectors[k][p] = c * kp - s * kq;
+                        vectors[k][q] = s * kp + c * kq;
+                    }
+                }
+            }
+        }
+
+        // * Sort the eigenvalues in decreasing order, with their eigenvectors
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|i, j| a[*j][*j].total_cmp(&a[*i][*i]));
+        let values = Vector::from(order.iter().map(|i| a[*i][*i]).collect::<Vec<f64>>());
+        let sorted = Matrix::from(
+            (0..size)
+                .map(|row| order.iter().map(|i| vectors[row][*i]).collect())
+                .collect::<Vec<Vec<f64>>>(),
+        );
+        Ok((values, sorted))
+    }
+}
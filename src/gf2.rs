@@ -0,0 +1,259 @@
+use std::fmt;
+
+use crate::{display, matrix::Matrix, modular::Zp, scalar::Scalar};
+
+const WORD_BITS: usize = 64;
+
+// Matrix over GF(2), each row is packed in 64-bit words so row operations are word-wise XORs
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitMatrix {
+    rows: usize,
+    cols: usize,
+    // Number of words of each row
+    stride: usize,
+    words: Vec<u64>,
+}
+
+// Displayed as a grid of 0 and 1
+impl fmt::Display for BitMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<Vec<u8>> = (0..self.rows)
+            .map(|row| {
+                (0..self.cols)
+                    .map(|column| self.get(row, column) as u8)
+                    .collect()
+            })
+            .collect();
+        let slices: Vec<&[u8]> = rows.iter().map(|row| row.as_slice()).collect();
+        display::write_rows(f, &slices)
+    }
+}
+
+impl Default for BitMatrix {
+    fn default() -> Self {
+        Self::new([0, 0])
+    }
+}
+
+// *> From
+
+// Non-zero values are set
+impl<const N: usize, const M: usize> From<[[u8; N]; M]> for BitMatrix {
+    fn from(rows: [[u8; N]; M]) -> Self {
+        let mut matrix = BitMatrix::new([M, N]);
+        for (row, values) in rows.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                matrix.set(row, column, *value != 0);
+            }
+        }
+        matrix
+    }
+}
+
+impl From<&Matrix<Zp<2>>> for BitMatrix {
+    fn from(matrix: &Matrix<Zp<2>>) -> Self {
+        let mut result = BitMatrix::new(matrix.shape());
+        for (row, values) in matrix.iter_rows().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                result.set(row, column, !value.is_zero());
+            }
+        }
+        result
+    }
+}
+
+// *< From
+
+impl BitMatrix {
+    pub fn new(shape: [usize; 2]) -> BitMatrix {
+        let stride = shape[1].div_ceil(WORD_BITS);
+        BitMatrix {
+            rows: shape[0],
+            cols: shape[1],
+            stride,
+            words: vec![0; stride * shape[0]],
+        }
+    }
+
+    // * Utility functions
+
+    pub fn identity(size: usize) -> BitMatrix {
+        let mut matrix = BitMatrix::new([size, size]);
+        for diagonal in 0..size {
+            matrix.set(diagonal, diagonal, true);
+        }
+        matrix
+    }
+
+    // Shape of the matrix, as [rows, columns]
+    pub fn shape(&self) -> [usize; 2] {
+        [self.rows, self.cols]
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> bool {
+        assert!(row < self.rows && column < self.cols, "Index out of bounds");
+        let word = self.words[row * self.stride + column / WORD_BITS];
+        (word >> (column % WORD_BITS)) & 1 == 1
+    }
+
+    pub fn set(&mut self, row: usize, column: usize, value: bool) {
+        assert!(row < self.rows && column < self.cols, "Index out of bounds");
+        let word = &mut self.words[row * self.stride + column / WORD_BITS];
+        let mask = 1 << (column % WORD_BITS);
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    // Number of set values in the row, the Hamming weight of a codeword
+    pub fn row_weight(&self, row: usize) -> usize {
+        self.row_words(row)
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn row_words(&self, row: usize) -> &[u64] {
+        &self.words[row * self.stride..(row + 1) * self.stride]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for word in 0..self.stride {
+            self.words
+                .swap(a * self.stride + word, b * self.stride + word);
+        }
+    }
+
+    // Rtarget = Rtarget + Rsource, which is a XOR in GF(2)
+    fn add_row(&mut self, target: usize, source: usize) {
+        for word in 0..self.stride {
+            self.words[target * self.stride + word] ^= self.words[source * self.stride + word];
+        }
+    }
+
+    // Convert to a generic matrix of Zp<2> values
+    pub fn to_matrix(&self) -> Matrix<Zp<2>> {
+        Matrix::from(
+            (0..self.rows)
+                .map(|row| {
+                    (0..self.cols)
+                        .map(|column| Zp::new(self.get(row, column) as i128))
+                        .collect()
+                })
+                .collect::<Vec<Vec<Zp<2>>>>(),
+        )
+    }
+
+    // * Subject functions
+
+    pub fn transpose(&self) -> BitMatrix {
+        let mut result = BitMatrix::new([self.cols, self.rows]);
+        for row in 0..self.rows {
+            for column in 0..self.cols {
+                if self.get(row, column) {
+                    result.set(column, row, true);
+                }
+            }
+        }
+        result
+    }
+
+    // Product over GF(2), an empty matrix is returned if the shapes are incompatible
+    pub fn mul_mat(&self, matrix: &BitMatrix) -> BitMatrix {
+        if self.cols != matrix.rows {
+            return BitMatrix::default();
+        }
+
+        let mut result = BitMatrix::new([self.rows, matrix.cols]);
+        for row in 0..self.rows {
+            for inner in 0..self.cols {
+                if self.get(row, inner) {
+                    let source = matrix.row_words(inner);
+                    for (word, value) in source.iter().enumerate() {
+                        result.words[row * result.stride + word] ^= value;
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    // Reduced row echelon form and the column of each pivot
+    fn reduce(&self, mut companion: Option<&mut BitMatrix>) -> (BitMatrix, Vec<usize>) {
+        let mut reduced = self.clone();
+        let mut pivots = vec![];
+        for column in 0..self.cols {
+            let row = pivots.len();
+            if row == self.rows {
+                break;
+            }
+            let Some(pivot) = (row..self.rows).find(|i| reduced.get(*i, column)) else {
+                continue;
+            };
+            reduced.swap_rows(pivot, row);
+            if let Some(companion) = companion.as_deref_mut() {
+                companion.swap_rows(pivot, row);
+            }
+            for i in 0..self.rows {
+                if i != row && reduced.get(i, column) {
+                    reduced.add_row(i, row);
+                    if let Some(companion) = companion.as_deref_mut() {
+                        companion.add_row(i, row);
+                    }
+                }
+            }
+            pivots.push(column);
+        }
+        (reduced, pivots)
+    }
+
+    pub fn row_echelon(&self) -> BitMatrix {
+        self.reduce(None).0
+    }
+
+    pub fn rank(&self) -> usize {
+        self.reduce(None).1.len()
+    }
+
+    // The determinant over GF(2) is 1 if the matrix is invertible, which is represented by `true`
+    pub fn determinant(&self) -> bool {
+        self.rows == self.cols && self.rank() == self.rows
+    }
+
+    pub fn inverse(&self) -> Result<BitMatrix, String> {
+        if self.rows != self.cols {
+            return Err(format!(
+                "Expected a square matrix, got shape {:?}",
+                self.shape()
+            ));
+        }
+        let mut result = BitMatrix::identity(self.rows);
+        let (_, pivots) = self.reduce(Some(&mut result));
+        if pivots.len() != self.rows {
+            return Err("Singular matrix".to_string());
+        }
+        Ok(result)
+    }
+
+    // Basis of the vectors x such that `self * x = 0`, with one vector in each row
+    // -- the null space of a parity-check matrix is the generator matrix of its code
+    pub fn null_space(&self) -> BitMatrix {
+        let (reduced, pivots) = self.reduce(None);
+        let free: Vec<usize> = (0..self.cols)
+            .filter(|column| !pivots.contains(column))
+            .collect();
+
+        let mut basis = BitMatrix::new([free.len(), self.cols]);
+        for (index, column) in free.iter().enumerate() {
+            basis.set(index, *column, true);
+            for (row, pivot) in pivots.iter().enumerate() {
+                if reduced.get(row, *column) {
+                    basis.set(index, *pivot, true);
+                }
+            }
+        }
+        basis
+    }
+}
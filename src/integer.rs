@@ -0,0 +1,160 @@
+use crate::{bigint::BigInt, matrix::Matrix, scalar::Scalar};
+
+// *> Unimodular operations
+
+// Replace the rows i and j by (a * Ri + b * Rj, c * Ri + d * Rj)
+fn combine_rows(matrix: &mut Matrix<BigInt>, [i, j]: [usize; 2], [a, b, c, d]: [&BigInt; 4]) {
+    for column in 0..matrix.shape()[1] {
+        let [x, y] = [&matrix[i][column], &matrix[j][column]];
+        let first = &(a * x) + &(b * y);
+        let second = &(c * x) + &(d * y);
+        matrix[i][column] = first;
+        matrix[j][column] = second;
+    }
+}
+
+// Rtarget = Rtarget - factor * Rsource
+fn sub_row_multiple(matrix: &mut Matrix<BigInt>, target: usize, source: usize, factor: &BigInt) {
+    for column in 0..matrix.shape()[1] {
+        let value = &matrix[target][column] - &(factor * &matrix[source][column]);
+        matrix[target][column] = value;
+    }
+}
+
+// Ctarget = Ctarget - factor * Csource
+fn sub_column_multiple(matrix: &mut Matrix<BigInt>, target: usize, source: usize, factor: &BigInt) {
+    for row in 0..matrix.shape()[0] {
+        let value = &matrix[row][target] - &(factor * &matrix[row][source]);
+        matrix[row][target] = value;
+    }
+}
+
+fn negate_row(matrix: &mut Matrix<BigInt>, row: usize) {
+    for column in 0..matrix.shape()[1] {
+        matrix[row][column] = -matrix[row][column].clone();
+    }
+}
+
+// *< Unimodular operations
+
+impl Matrix<BigInt> {
+    // Row-style Hermite normal form H with the unimodular matrix U such that U * self = H
+    // H is upper triangular, each pivot is positive and the values above a pivot are in [0, pivot)
+    pub fn hermite_normal_form(&self) -> (Matrix<BigInt>, Matrix<BigInt>) {
+        let [rows, cols] = self.shape();
+        let mut hermite = self.clone();
+        let mut transform = Matrix::identity(rows, BigInt::one());
+
+        let mut pivot = 0;
+        for column in 0..cols {
+            if pivot == rows {
+                break;
+            }
+
+            // * Move the gcd of the column to the pivot row and clear the values below it
+            for i in (pivot + 1)..rows {
+                if hermite[i][column].is_zero() {
+                    continue;
+                }
+                let a = hermite[pivot][column].clone();
+                let b = hermite[i][column].clone();
+                let (gcd, x, y) = a.extended_gcd(&b);
+                let coefficients = [&x, &y, &-(&b / &gcd), &(&a / &gcd)];
+                combine_rows(&mut hermite, [pivot, i], coefficients);
+                combine_rows(&mut transform, [pivot, i], coefficients);
+            }
+            if hermite[pivot][column].is_zero() {
+                continue;
+            }
+            if hermite[pivot][column].is_negative() {
+                negate_row(&mut hermite, pivot);
+                negate_row(&mut transform, pivot);
+            }
+
+            // * Reduce the values above the pivot
+            for i in 0..pivot {
+                let factor = hermite[i][column].div_euclid(&hermite[pivot][column]);
+                if !factor.is_zero() {
+                    sub_row_multiple(&mut hermite, i, pivot, &factor);
+                    sub_row_multiple(&mut transform, i, pivot, &factor);
+                }
+            }
+            pivot += 1;
+        }
+
+        (hermite, transform)
+    }
+
+    // Smith normal form D with the unimodular matrices U and V such that U * self * V = D
+    // D is diagonal, the diagonal values are positive or zero and each one divides the next
+    pub fn smith_normal_form(&self) -> (Matrix<BigInt>, Matrix<BigInt>, Matrix<BigInt>) {
+        let [rows, cols] = self.shape();
+        let mut smith = self.clone();
+        let mut left = Matrix::identity(rows, BigInt::one());
+        let mut right = Matrix::identity(cols, BigInt::one());
+
+        for t in 0..rows.min(cols) {
+            loop {
+                // * Move the smallest non-zero value of the remaining block to the pivot
+                let mut smallest: Option<[usize; 2]> = None;
+                for i in t..rows {
+                    for j in t..cols {
+                        let value = &smith[i][j];
+                        if !value.is_zero()
+                            && smallest.is_none_or(|[r, c]| value.abs() < smith[r][c].abs())
+                        {
+                            smallest = Some([i, j]);
+                        }
+                    }
+                }
+                let Some([i, j]) = smallest else {
+                    // The remaining block is zero
+                    return (smith, left, right);
+                };
+                smith.swap_rows(t, i);
+                left.swap_rows(t, i);
+                smith.swap_cols(t, j);
+                right.swap_cols(t, j);
+
+                // * Clear the pivot row and column, a remainder means a smaller pivot exists
+                let mut cleared = true;
+                for i in (t + 1)..rows {
+                    let factor = smith[i][t].div_euclid(&smith[t][t]);
+                    sub_row_multiple(&mut smith, i, t, &factor);
+                    sub_row_multiple(&mut left, i, t, &factor);
+                    cleared &= smith[i][t].is_zero();
+                }
+                for j in (t + 1)..cols {
+                    let factor = smith[t][j].div_euclid(&smith[t][t]);
+                    sub_column_multiple(&mut smith, j, t, &factor);
+                    sub_column_multiple(&mut right, j, t, &factor);
+                    cleared &= smith[t][j].is_zero();
+                }
+                if !cleared {
+                    continue;
+                }
+
+                // * The pivot must divide all of the remaining values
+                // -- otherwise add the row to the pivot row, which leaves a smaller remainder
+                let indivisible = ((t + 1)..rows).find(|i| {
+                    ((t + 1)..cols).any(|j| !smith[*i][j].rem_euclid(&smith[t][t]).is_zero())
+                });
+                match indivisible {
+                    Some(i) => {
+                        let one = BigInt::one();
+                        let zero = BigInt::zero();
+                        combine_rows(&mut smith, [t, i], [&one, &one, &zero, &one]);
+                        combine_rows(&mut left, [t, i], [&one, &one, &zero, &one]);
+                    }
+                    None => break,
+                }
+            }
+            if smith[t][t].is_negative() {
+                negate_row(&mut smith, t);
+                negate_row(&mut left, t);
+            }
+        }
+
+        (smith, left, right)
+    }
+}
@@ -0,0 +1,411 @@
+// Compact self-describing binary format
+//
+// Record layout:
+// - magic `MTRX`
+// - version (u8)
+// - flags (u8): bit 0 for big endian values, bit 1 if a checksum follows the values
+// - kind (u8): 0 for a matrix, 1 for a vector
+// - scalar type (u8): 0 for f64, 1 for f32
+// - rows and columns (u64 each)
+// - the values in row-major order
+// - the CRC-32 of everything after the magic (u32), if enabled
+//
+// An archive starts with the magic `MTRA` and its version, followed by named records:
+// a marker byte (1), the length of the name (u32 little endian), the UTF-8 name and the record.
+// The archive ends with a marker byte of 0.
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use super::{Endian, Error};
+use crate::{matrix::Matrix, vector::Vector};
+
+const MAGIC: &[u8; 4] = b"MTRX";
+const ARCHIVE_MAGIC: &[u8; 4] = b"MTRA";
+const VERSION: u8 = 1;
+
+const FLAG_BIG_ENDIAN: u8 = 1;
+const FLAG_CHECKSUM: u8 = 1 << 1;
+
+const KIND_MATRIX: u8 = 0;
+const KIND_VECTOR: u8 = 1;
+
+// Type used to store the values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScalarType {
+    #[default]
+    F64,
+    // Half the size, but values are rounded to the nearest f32
+    F32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryOptions {
+    pub scalar: ScalarType,
+    pub endian: Endian,
+    pub checksum: bool,
+}
+
+impl Default for BinaryOptions {
+    fn default() -> Self {
+        BinaryOptions {
+            scalar: ScalarType::F64,
+            endian: Endian::Little,
+            checksum: true,
+        }
+    }
+}
+
+// * Checksum
+
+// CRC-32 (IEEE 802.3) lookup table
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut value = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 == 1 {
+                0xEDB8_8320 ^ (value >> 1)
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[index] = value;
+        index += 1;
+    }
+    table
+};
+
+fn crc32(crc: u32, bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!crc, |crc, byte| {
+        CRC_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+// Writer that keeps the checksum of everything written through it
+struct ChecksumWriter<W: Write> {
+    writer: W,
+    crc: u32,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.crc = crc32(self.crc, bytes);
+        self.writer.write_all(bytes)?;
+        Ok(())
+    }
+}
+
+// Reader that keeps the checksum of everything read through it
+struct ChecksumReader<R: Read> {
+    reader: R,
+    crc: u32,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    fn read<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut bytes = [0; N];
+        self.reader.read_exact(&mut bytes)?;
+        self.crc = crc32(self.crc, &bytes);
+        Ok(bytes)
+    }
+}
+
+// * Records
+
+fn write_record<W: Write>(
+    writer: W,
+    kind: u8,
+    shape: [usize; 2],
+    values: impl Iterator<Item = f64>,
+    options: &BinaryOptions,
+) -> Result<(), Error> {
+    let mut writer = ChecksumWriter { writer, crc: 0 };
+    writer.writer.write_all(MAGIC)?;
+
+    let mut flags = 0;
+    if options.endian == Endian::Big {
+        flags |= FLAG_BIG_ENDIAN;
+    }
+    if options.checksum {
+        flags |= FLAG_CHECKSUM;
+    }
+    let scalar = match options.scalar {
+        ScalarType::F64 => 0,
+        ScalarType::F32 => 1,
+    };
+    writer.write(&[VERSION, flags, kind, scalar])?;
+
+    macro_rules! bytes {
+        ($value:expr) => {
+            match options.endian {
+                Endian::Little => $value.to_le_bytes(),
+                Endian::Big => $value.to_be_bytes(),
+            }
+        };
+    }
+    writer.write(&bytes!(shape[0] as u64))?;
+    writer.write(&bytes!(shape[1] as u64))?;
+    for value in values {
+        match options.scalar {
+            ScalarType::F64 => writer.write(&bytes!(value))?,
+            ScalarType::F32 => writer.write(&bytes!(value as f32))?,
+        }
+    }
+
+    if options.checksum {
+        let crc = writer.crc;
+        writer.write(&bytes!(crc))?;
+    }
+    Ok(())
+}
+
+// Read a record, the values are returned in row-major order with the kind and shape of the record
+fn read_record<R: Read>(reader: R) -> Result<(u8, [usize; 2], Vec<f64>), Error> {
+    let mut reader = ChecksumReader { reader, crc: 0 };
+    let mut magic = [0; 4];
+    reader.reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(Error::Format("Not a binary matrix record".to_string()));
+    }
+
+    let [version, flags, kind, scalar] = reader.read::<4>()?;
+    if version != VERSION {
+        return Err(Error::Format(format!(
+            "Unsupported binary format version {}",
+            version
+        )));
+    }
+    if flags & !(FLAG_BIG_ENDIAN | FLAG_CHECKSUM) != 0 {
+        return Err(Error::Format(format!("Unknown flags {:#010b}", flags)));
+    }
+    if kind != KIND_MATRIX && kind != KIND_VECTOR {
+        return Err(Error::Format(format!("Unknown record kind {}", kind)));
+    }
+    let scalar = match scalar {
+        0 => ScalarType::F64,
+        1 => ScalarType::F32,
+        _ => return Err(Error::Format(format!("Unknown scalar type {}", scalar))),
+    };
+    let big_endian = flags & FLAG_BIG_ENDIAN != 0;
+
+    macro_rules! value {
+        ($type:ty, $size:expr) => {{
+            let bytes = reader.read::<$size>()?;
+            if big_endian {
+                <$type>::from_be_bytes(bytes)
+            } else {
+                <$type>::from_le_bytes(bytes)
+            }
+        }};
+    }
+    let rows = usize::try_from(value!(u64, 8))
+        .map_err(|_| Error::Format("Shape too large for this platform".to_string()))?;
+    let cols = usize::try_from(value!(u64, 8))
+        .map_err(|_| Error::Format("Shape too large for this platform".to_string()))?;
+    // The rows are allocated even if there are no values
+    super::check_shape([rows, cols])?;
+    let count = rows * cols;
+
+    let mut values = Vec::with_capacity(count.min(1 << 20));
+    for _ in 0..count {
+        values.push(match scalar {
+            ScalarType::F64 => value!(f64, 8),
+            ScalarType::F32 => value!(f32, 4) as f64,
+        });
+    }
+
+    if flags & FLAG_CHECKSUM != 0 {
+        let expected = reader.crc;
+        let crc = value!(u32, 4);
+        if crc != expected {
+            return Err(Error::Format(format!(
+                "Checksum mismatch, expected {:#010x} got {:#010x}",
+                expected, crc
+            )));
+        }
+    }
+
+    Ok((kind, [rows, cols], values))
+}
+
+fn to_rows(shape: [usize; 2], values: Vec<f64>) -> Vec<Vec<f64>> {
+    if shape[1] == 0 {
+        return vec![vec![]; shape[0]];
+    }
+    values.chunks(shape[1]).map(|row| row.to_vec()).collect()
+}
+
+// Write a matrix as a single record
+pub fn write_matrix<W: Write>(
+    writer: W,
+    matrix: &Matrix,
+    options: &BinaryOptions,
+) -> Result<(), Error> {
+    let values = matrix.all().iter().flatten().copied();
+    write_record(writer, KIND_MATRIX, matrix.shape(), values, options)
+}
+
+// Write a vector as a single record
+pub fn write_vector<W: Write>(
+    writer: W,
+    vector: &Vector,
+    options: &BinaryOptions,
+) -> Result<(), Error> {
+    let values = vector.all().iter().copied();
+    write_record(writer, KIND_VECTOR, [1, vector.size()], values, options)
+}
+
+// Read a matrix from a single record, vectors are read as a single row
+pub fn read_matrix<R: Read>(reader: R) -> Result<Matrix, Error> {
+    let (_, shape, values) = read_record(reader)?;
+    Ok(Matrix::from(to_rows(shape, values)))
+}
+
+// Read a vector from a single record, matrices are accepted if they have a single row or column
+pub fn read_vector<R: Read>(reader: R) -> Result<Vector, Error> {
+    let (kind, [rows, cols], values) = read_record(reader)?;
+    if kind == KIND_MATRIX && rows > 1 && cols != 1 {
+        return Err(Error::Format(format!(
+            "Expected a single row or column, got shape {:?}",
+            [rows, cols]
+        )));
+    }
+    Ok(Vector::from(values))
+}
+
+// * Archive
+
+// Write several named matrices to a single stream
+pub struct ArchiveWriter<W: Write> {
+    writer: W,
+    options: BinaryOptions,
+}
+
+impl<W: Write> ArchiveWriter<W> {
+    pub fn new(mut writer: W, options: &BinaryOptions) -> Result<ArchiveWriter<W>, Error> {
+        writer.write_all(ARCHIVE_MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        Ok(ArchiveWriter {
+            writer,
+            options: *options,
+        })
+    }
+
+    pub fn write(&mut self, name: &str, matrix: &Matrix) -> Result<(), Error> {
+        let length =
+            u32::try_from(name.len()).map_err(|_| Error::Format("Name too long".to_string()))?;
+        self.writer.write_all(&[1])?;
+        self.writer.write_all(&length.to_le_bytes())?;
+        self.writer.write_all(name.as_bytes())?;
+        write_matrix(&mut self.writer, matrix, &self.options)
+    }
+
+    // Write the end of the archive and return the underlying writer
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.writer.write_all(&[0])?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+// Read the named matrices of an archive, one at a time
+pub struct ArchiveReader<R: Read> {
+    reader: R,
+    finished: bool,
+}
+
+impl<R: Read> ArchiveReader<R> {
+    pub fn new(mut reader: R) -> Result<ArchiveReader<R>, Error> {
+        let mut magic = [0; 5];
+        reader.read_exact(&mut magic)?;
+        if &magic[..4] != ARCHIVE_MAGIC {
+            return Err(Error::Format("Not a binary matrix archive".to_string()));
+        }
+        if magic[4] != VERSION {
+            return Err(Error::Format(format!(
+                "Unsupported binary format version {}",
+                magic[4]
+            )));
+        }
+        Ok(ArchiveReader {
+            reader,
+            finished: false,
+        })
+    }
+
+    fn read_entry(&mut self) -> Result<Option<(String, Matrix)>, Error> {
+        let mut marker = [0; 1];
+        self.reader.read_exact(&mut marker)?;
+        match marker[0] {
+            0 => return Ok(None),
+            1 => {}
+            marker => return Err(Error::Format(format!("Invalid entry marker {}", marker))),
+        }
+
+        let mut length = [0; 4];
+        self.reader.read_exact(&mut length)?;
+        // Read through `take` so an invalid length can't allocate more than the input
+        let length = u32::from_le_bytes(length) as usize;
+        let mut name = vec![];
+        (&mut self.reader)
+            .take(length as u64)
+            .read_to_end(&mut name)?;
+        if name.len() != length {
+            return Err(Error::Format(format!(
+                "Expected an entry name of {} bytes, got {}",
+                length,
+                name.len()
+            )));
+        }
+        let name = String::from_utf8(name)
+            .map_err(|_| Error::Format("Invalid entry name encoding".to_string()))?;
+        let matrix = read_matrix(&mut self.reader)?;
+        Ok(Some((name, matrix)))
+    }
+}
+
+impl<R: Read> Iterator for ArchiveReader<R> {
+    type Item = Result<(String, Matrix), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.read_entry() {
+            Ok(Some(entry)) => Some(Ok(entry)),
+            Ok(None) => {
+                self.finished = true;
+                None
+            }
+            Err(error) => {
+                self.finished = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+// Write the named matrices to an archive file
+pub fn save_archive<P: AsRef<Path>>(
+    path: P,
+    entries: &[(&str, &Matrix)],
+    options: &BinaryOptions,
+) -> Result<(), Error> {
+    let mut archive = ArchiveWriter::new(BufWriter::new(File::create(path)?), options)?;
+    for (name, matrix) in entries {
+        archive.write(name, matrix)?;
+    }
+    archive.finish()?;
+    Ok(())
+}
+
+// Read all of the named matrices of an archive file
+pub fn load_archive<P: AsRef<Path>>(path: P) -> Result<Vec<(String, Matrix)>, Error> {
+    ArchiveReader::new(BufReader::new(File::open(path)?))?.collect()
+}
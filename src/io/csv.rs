@@ -0,0 +1,204 @@
+// Comma-separated values, with one row of the matrix per line
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use super::Error;
+use crate::matrix::Matrix;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    // Character separating the values of a line
+    pub delimiter: char,
+    // The first line contains the name of the columns
+    pub header: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            header: false,
+        }
+    }
+}
+
+// Split a line in fields, handling fields surrounded by quotes with `""` as an escaped quote
+fn split_fields(line: &str, delimiter: char, number: usize) -> Result<Vec<String>, Error> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut characters = line.chars().peekable();
+    while let Some(character) = characters.next() {
+        if quoted {
+            if character == '"' {
+                if characters.peek() == Some(&'"') {
+                    field.push('"');
+                    characters.next();
+                } else {
+                    quoted = false;
+                }
+            } else {
+                field.push(character);
+            }
+        } else if character == '"' && field.trim().is_empty() {
+            field.clear();
+            quoted = true;
+        } else if character == delimiter {
+            fields.push(std::mem::take(&mut field));
+        } else {
+            field.push(character);
+        }
+    }
+    if quoted {
+        return Err(Error::Format(format!("Unclosed quote at line {}", number)));
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn read(reader: impl BufRead, options: &CsvOptions) -> Result<(Vec<String>, Matrix), Error> {
+    let mut header = vec![];
+    let mut header_read = !options.header;
+    let mut rows: Vec<Vec<f64>> = vec![];
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fields = split_fields(&line, options.delimiter, number)?;
+        if !header_read {
+            header = fields
+                .iter()
+                .map(|field| field.trim().to_string())
+                .collect();
+            header_read = true;
+            continue;
+        }
+
+        let row = fields
+            .iter()
+            .enumerate()
+            .map(|(column, field)| {
+                field.trim().parse::<f64>().map_err(|_| {
+                    Error::Format(format!(
+                        "Invalid value '{}' at line {}, column {}",
+                        field.trim(),
+                        number,
+                        column + 1
+                    ))
+                })
+            })
+            .collect::<Result<Vec<f64>, Error>>()?;
+
+        let expected = rows.first().map_or(header.len(), |first| first.len());
+        if expected > 0 && row.len() != expected {
+            return Err(Error::Format(format!(
+                "Line {} has {} values, expected {}",
+                number,
+                row.len(),
+                expected
+            )));
+        }
+        rows.push(row);
+    }
+
+    Ok((header, Matrix::from(rows)))
+}
+
+// Read a matrix with one row per line, the header line is skipped if there is one
+pub fn read_csv<R: BufRead>(reader: R, options: &CsvOptions) -> Result<Matrix, Error> {
+    Ok(read(reader, options)?.1)
+}
+
+// Read a matrix and the name of its columns from the first line
+pub fn read_csv_with_header<R: BufRead>(
+    reader: R,
+    options: &CsvOptions,
+) -> Result<(Vec<String>, Matrix), Error> {
+    let options = CsvOptions {
+        header: true,
+        ..*options
+    };
+    read(reader, &options)
+}
+
+fn write(
+    mut writer: impl Write,
+    header: Option<&[&str]>,
+    matrix: &Matrix,
+    options: &CsvOptions,
+) -> Result<(), Error> {
+    let separator = options.delimiter.to_string();
+    if let Some(header) = header {
+        if header.len() != matrix.shape()[1] {
+            return Err(Error::Format(format!(
+                "Header has {} columns, expected {}",
+                header.len(),
+                matrix.shape()[1]
+            )));
+        }
+        let fields: Vec<String> = header
+            .iter()
+            .map(|field| quote_field(field, options.delimiter))
+            .collect();
+        writeln!(writer, "{}", fields.join(&separator))?;
+    }
+    for row in matrix.iter_rows() {
+        let fields: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+        writeln!(writer, "{}", fields.join(&separator))?;
+    }
+    Ok(())
+}
+
+// Write a matrix with one row per line
+// If the options expect a header, the index of each column is used as its name
+pub fn write_csv<W: Write>(writer: W, matrix: &Matrix, options: &CsvOptions) -> Result<(), Error> {
+    if options.header {
+        let names: Vec<String> = (0..matrix.shape()[1])
+            .map(|column| column.to_string())
+            .collect();
+        let header: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+        return write(writer, Some(&header), matrix, options);
+    }
+    write(writer, None, matrix, options)
+}
+
+// Write a matrix with one row per line, preceded by the name of the columns
+pub fn write_csv_with_header<W: Write>(
+    writer: W,
+    header: &[&str],
+    matrix: &Matrix,
+    options: &CsvOptions,
+) -> Result<(), Error> {
+    write(writer, Some(header), matrix, options)
+}
+
+// Read a matrix from a CSV file
+pub fn load_csv<P: AsRef<Path>>(path: P, options: &CsvOptions) -> Result<Matrix, Error> {
+    read_csv(BufReader::new(File::open(path)?), options)
+}
+
+// Write a matrix to a CSV file
+pub fn save_csv<P: AsRef<Path>>(
+    path: P,
+    matrix: &Matrix,
+    options: &CsvOptions,
+) -> Result<(), Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_csv(&mut writer, matrix, options)?;
+    writer.flush()?;
+    Ok(())
+}
@@ -0,0 +1,349 @@
+// Matrix Market exchange format
+// @see https://math.nist.gov/MatrixMarket/formats.html
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use super::Error;
+use crate::matrix::Matrix;
+
+// Layout of the values in the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarketFormat {
+    // Only the non-zero entries, as `row column value`
+    #[default]
+    Coordinate,
+    // All of the entries, in column-major order
+    Array,
+}
+
+// Type of the values in the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarketField {
+    #[default]
+    Real,
+    Integer,
+    // No values, the listed entries are equal to 1 (coordinate format only)
+    Pattern,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarketSymmetry {
+    #[default]
+    General,
+    // Only the lower triangle (with the diagonal) is stored
+    Symmetric,
+}
+
+// Header of a Matrix Market file, also used as the options when writing one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MarketOptions {
+    pub format: MarketFormat,
+    pub field: MarketField,
+    pub symmetry: MarketSymmetry,
+}
+
+fn parse_header(line: &str) -> Result<MarketOptions, Error> {
+    let words: Vec<String> = line
+        .split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect();
+    if words.len() != 5 || words[0] != "%%matrixmarket" {
+        return Err(Error::Format(format!(
+            "Invalid Matrix Market header '{}'",
+            line
+        )));
+    }
+    if words[1] != "matrix" {
+        return Err(Error::Format(format!("Unsupported object '{}'", words[1])));
+    }
+
+    let format = match words[2].as_str() {
+        "coordinate" => MarketFormat::Coordinate,
+        "array" => MarketFormat::Array,
+        other => return Err(Error::Format(format!("Unsupported format '{}'", other))),
+    };
+    let field = match words[3].as_str() {
+        "real" | "double" => MarketField::Real,
+        "integer" => MarketField::Integer,
+        "pattern" if format == MarketFormat::Coordinate => MarketField::Pattern,
+        other => return Err(Error::Format(format!("Unsupported field '{}'", other))),
+    };
+    let symmetry = match words[4].as_str() {
+        "general" => MarketSymmetry::General,
+        "symmetric" => MarketSymmetry::Symmetric,
+        other => return Err(Error::Format(format!("Unsupported symmetry '{}'", other))),
+    };
+
+    Ok(MarketOptions {
+        format,
+        field,
+        symmetry,
+    })
+}
+
+fn parse_index(word: Option<&str>, size: usize, line: usize) -> Result<usize, Error> {
+    let index = word
+        .and_then(|word| word.parse::<usize>().ok())
+        .ok_or_else(|| Error::Format(format!("Invalid index at line {}", line)))?;
+    if index == 0 || index > size {
+        return Err(Error::Format(format!(
+            "Index {} out of bounds at line {}",
+            index, line
+        )));
+    }
+    Ok(index - 1)
+}
+
+fn parse_value(word: Option<&str>, field: MarketField, line: usize) -> Result<f64, Error> {
+    let word = word.ok_or_else(|| Error::Format(format!("Missing value at line {}", line)))?;
+    let value = match field {
+        MarketField::Integer => word.parse::<i64>().map(|value| value as f64).ok(),
+        _ => word.parse::<f64>().ok(),
+    };
+    value.ok_or_else(|| Error::Format(format!("Invalid value '{}' at line {}", word, line)))
+}
+
+// Read a matrix in the Matrix Market format
+pub fn read_matrix_market<R: BufRead>(reader: R) -> Result<Matrix, Error> {
+    let mut lines = reader.lines().enumerate();
+
+    // * Header
+    let header = match lines.next() {
+        Some((_, line)) => line?,
+        None => return Err(Error::Format("Empty Matrix Market file".to_string())),
+    };
+    let options = parse_header(&header)?;
+
+    // * Content lines, without comments and empty lines
+    let mut content = lines.filter_map(|(index, line)| match line {
+        Ok(line) => {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('%') {
+                None
+            } else {
+                Some(Ok((index + 1, trimmed.to_string())))
+            }
+        }
+        Err(error) => Some(Err(error)),
+    });
+
+    let (size_line, size) = content
+        .next()
+        .ok_or_else(|| Error::Format("Missing size line".to_string()))??;
+    let sizes = size
+        .split_whitespace()
+        .map(|word| word.parse::<usize>())
+        .collect::<Result<Vec<usize>, _>>()
+        .map_err(|_| Error::Format(format!("Invalid size line at line {}", size_line)))?;
+    let expected_sizes = match options.format {
+        MarketFormat::Coordinate => 3,
+        MarketFormat::Array => 2,
+    };
+    if sizes.len() != expected_sizes {
+        return Err(Error::Format(format!(
+            "Invalid size line at line {}",
+            size_line
+        )));
+    }
+    let [rows, cols] = [sizes[0], sizes[1]];
+    let symmetric = options.symmetry == MarketSymmetry::Symmetric;
+    if symmetric && rows != cols {
+        return Err(Error::Format(format!(
+            "Symmetric matrix must be square, got shape {:?}",
+            [rows, cols]
+        )));
+    }
+
+    super::check_shape([rows, cols])?;
+    let mut matrix = Matrix::new([rows, cols]);
+    match options.format {
+        MarketFormat::Coordinate => {
+            let entries = sizes[2];
+            for _ in 0..entries {
+                let (line, entry) = content.next().ok_or_else(|| {
+                    Error::Format(format!("Expected {} entries, file ended early", entries))
+                })??;
+                let mut words = entry.split_whitespace();
+                let row = parse_index(words.next(), rows, line)?;
+                let column = parse_index(words.next(), cols, line)?;
+                let value = match options.field {
+                    MarketField::Pattern => 1.,
+                    field => parse_value(words.next(), field, line)?,
+                };
+                if symmetric && column > row {
+                    return Err(Error::Format(format!(
+                        "Entry above the diagonal in a symmetric matrix at line {}",
+                        line
+                    )));
+                }
+                matrix[row][column] = value;
+                if symmetric {
+                    matrix[column][row] = value;
+                }
+            }
+        }
+        MarketFormat::Array => {
+            // Column-major order, only the lower triangle for symmetric matrices
+            for column in 0..cols {
+                let first_row = if symmetric { column } else { 0 };
+                for row in first_row..rows {
+                    let (line, entry) = content.next().ok_or_else(|| {
+                        Error::Format("Missing entries, file ended early".to_string())
+                    })??;
+                    let value = parse_value(Some(&entry), options.field, line)?;
+                    matrix[row][column] = value;
+                    if symmetric {
+                        matrix[column][row] = value;
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(entry) = content.next() {
+        let (line, _) = entry?;
+        return Err(Error::Format(format!("Unexpected entry at line {}", line)));
+    }
+
+    Ok(matrix)
+}
+
+fn format_value(value: f64, field: MarketField) -> Result<String, Error> {
+    match field {
+        MarketField::Integer => {
+            if value.fract() != 0. || !value.is_finite() {
+                return Err(Error::Format(format!(
+                    "Value {} can't be written as an integer",
+                    value
+                )));
+            }
+            Ok(format!("{}", value as i64))
+        }
+        _ => Ok(format!("{:e}", value)),
+    }
+}
+
+// Write a matrix in the Matrix Market format
+// Symmetric matrices are validated and only their lower triangle is written
+// The pattern field has no values, so it only accepts matrices of zeros and ones
+pub fn write_matrix_market<W: Write>(
+    mut writer: W,
+    matrix: &Matrix,
+    options: &MarketOptions,
+) -> Result<(), Error> {
+    let [rows, cols] = matrix.shape();
+    let symmetric = options.symmetry == MarketSymmetry::Symmetric;
+
+    // * Validate
+    if options.format == MarketFormat::Array && options.field == MarketField::Pattern {
+        return Err(Error::Format(
+            "The pattern field is only available with the coordinate format".to_string(),
+        ));
+    }
+    if options.field == MarketField::Pattern {
+        if let Some(value) = matrix.iter().find(|value| **value != 0. && **value != 1.) {
+            return Err(Error::Format(format!(
+                "Value {} can't be written as a pattern, only 0 and 1 can",
+                value
+            )));
+        }
+    }
+    if symmetric {
+        if rows != cols {
+            return Err(Error::Format(format!(
+                "Symmetric matrix must be square, got shape {:?}",
+                [rows, cols]
+            )));
+        }
+        for row in 0..rows {
+            for column in 0..row {
+                if matrix[row][column] != matrix[column][row] {
+                    return Err(Error::Format(format!(
+                        "Matrix is not symmetric at {:?}",
+                        [row, column]
+                    )));
+                }
+            }
+        }
+    }
+
+    // * Header
+    let format = match options.format {
+        MarketFormat::Coordinate => "coordinate",
+        MarketFormat::Array => "array",
+    };
+    let field = match options.field {
+        MarketField::Real => "real",
+        MarketField::Integer => "integer",
+        MarketField::Pattern => "pattern",
+    };
+    let symmetry = match options.symmetry {
+        MarketSymmetry::General => "general",
+        MarketSymmetry::Symmetric => "symmetric",
+    };
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix {} {} {}",
+        format, field, symmetry
+    )?;
+
+    // * Entries, in column-major order
+    let stored = |row: usize, column: usize| !symmetric || row >= column;
+    match options.format {
+        MarketFormat::Coordinate => {
+            let mut entries = vec![];
+            for column in 0..cols {
+                for row in 0..rows {
+                    if stored(row, column) && matrix[row][column] != 0. {
+                        entries.push([row, column]);
+                    }
+                }
+            }
+            writeln!(writer, "{} {} {}", rows, cols, entries.len())?;
+            for [row, column] in entries {
+                if options.field == MarketField::Pattern {
+                    writeln!(writer, "{} {}", row + 1, column + 1)?;
+                } else {
+                    let value = format_value(matrix[row][column], options.field)?;
+                    writeln!(writer, "{} {} {}", row + 1, column + 1, value)?;
+                }
+            }
+        }
+        MarketFormat::Array => {
+            writeln!(writer, "{} {}", rows, cols)?;
+            for column in 0..cols {
+                for row in 0..rows {
+                    if stored(row, column) {
+                        writeln!(
+                            writer,
+                            "{}",
+                            format_value(matrix[row][column], options.field)?
+                        )?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Read a matrix from a Matrix Market file
+pub fn load_matrix_market<P: AsRef<Path>>(path: P) -> Result<Matrix, Error> {
+    read_matrix_market(BufReader::new(File::open(path)?))
+}
+
+// Write a matrix to a Matrix Market file
+pub fn save_matrix_market<P: AsRef<Path>>(
+    path: P,
+    matrix: &Matrix,
+    options: &MarketOptions,
+) -> Result<(), Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_matrix_market(&mut writer, matrix, options)?;
+    writer.flush()?;
+    Ok(())
+}
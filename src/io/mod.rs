@@ -0,0 +1,95 @@
+use std::{error, fmt, io};
+
+use crate::{matrix::Matrix, vector::Vector};
+
+pub mod binary;
+pub mod csv;
+pub mod market;
+pub mod npy;
+
+// Largest number of elements of a matrix read from a file, 2 GiB of values
+const MAX_ELEMENTS: usize = 1 << 28;
+
+// Error returned when reading or writing a file
+#[derive(Debug)]
+pub enum Error {
+    // The underlying reader or writer failed
+    Io(io::Error),
+    // The content doesn't follow the format, or the matrix can't be written in the requested format
+    Format(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{}", error),
+            Error::Format(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Format(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+// The shape read from a file can't be trusted, the matrix is dense so its number of elements is capped
+// -- an empty dimension counts as one, the rows are still allocated when there are no columns
+pub(crate) fn check_shape(shape: [usize; 2]) -> Result<(), Error> {
+    let [rows, cols] = shape;
+    if rows
+        .max(1)
+        .checked_mul(cols.max(1))
+        .is_none_or(|count| count > MAX_ELEMENTS)
+    {
+        return Err(Error::Format(format!(
+            "Matrix of shape {:?} is too large, at most {} elements are supported",
+            shape, MAX_ELEMENTS
+        )));
+    }
+    Ok(())
+}
+
+// Byte order of the values in binary formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+}
+
+impl Endian {
+    // Byte order of the current platform
+    pub fn native() -> Endian {
+        if cfg!(target_endian = "big") {
+            Endian::Big
+        } else {
+            Endian::Little
+        }
+    }
+}
+
+// Convert a matrix read from a file to a vector
+// The matrix must have a single row or a single column
+pub fn to_vector(matrix: &Matrix) -> Result<Vector, Error> {
+    let [rows, cols] = matrix.shape();
+    if rows <= 1 || cols == 1 {
+        return Ok(Vector::from(
+            matrix.all().iter().flatten().copied().collect::<Vec<f64>>(),
+        ));
+    }
+    Err(Error::Format(format!(
+        "Expected a single row or column, got shape {:?}",
+        [rows, cols]
+    )))
+}
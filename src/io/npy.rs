@@ -0,0 +1,443 @@
+// NumPy `.npy` binary format
+// @see https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use super::{Endian, Error};
+use crate::{matrix::Matrix, vector::Vector};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+// The total size of the preamble and the header is padded to a multiple of this alignment
+const ALIGNMENT: usize = 64;
+
+// Type of the values stored in the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NpyType {
+    F32,
+    #[default]
+    F64,
+    I32,
+    I64,
+}
+
+impl NpyType {
+    fn size(&self) -> usize {
+        match self {
+            NpyType::F32 | NpyType::I32 => 4,
+            NpyType::F64 | NpyType::I64 => 8,
+        }
+    }
+
+    fn code(&self) -> &str {
+        match self {
+            NpyType::F32 => "f4",
+            NpyType::F64 => "f8",
+            NpyType::I32 => "i4",
+            NpyType::I64 => "i8",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NpyOptions {
+    pub dtype: NpyType,
+    pub endian: Endian,
+    // Store the values in column-major order instead of row-major order
+    pub fortran_order: bool,
+}
+
+// Content of a file, with the values in the order of the file
+struct Array {
+    shape: Vec<usize>,
+    fortran_order: bool,
+    values: Vec<f64>,
+}
+
+// * Header
+
+enum HeaderValue {
+    Text(String),
+    Boolean(bool),
+    Tuple(Vec<usize>),
+}
+
+fn header_error(header: &str) -> Error {
+    Error::Format(format!("Invalid .npy header '{}'", header.trim_end()))
+}
+
+// Parse the Python dictionary literal of the header
+// Only the syntax produced by NumPy is handled: quoted keys, strings, booleans and tuples of integers
+fn parse_header(header: &str) -> Result<Vec<(String, HeaderValue)>, Error> {
+    let content = header
+        .trim()
+        .strip_prefix('{')
+        .and_then(|content| content.strip_suffix('}'))
+        .ok_or_else(|| header_error(header))?;
+
+    let mut entries = vec![];
+    let mut rest = content.trim_start();
+    while !rest.is_empty() {
+        // * Key
+        let quote = rest.chars().next().ok_or_else(|| header_error(header))?;
+        if quote != '\'' && quote != '"' {
+            return Err(header_error(header));
+        }
+        let end = rest[1..].find(quote).ok_or_else(|| header_error(header))? + 1;
+        let key = rest[1..end].to_string();
+        rest = rest[end + 1..]
+            .trim_start()
+            .strip_prefix(':')
+            .ok_or_else(|| header_error(header))?
+            .trim_start();
+
+        // * Value
+        let (value, length) = if rest.starts_with('\'') || rest.starts_with('"') {
+            let quote = &rest[..1];
+            let end = rest[1..].find(quote).ok_or_else(|| header_error(header))? + 1;
+            (HeaderValue::Text(rest[1..end].to_string()), end + 1)
+        } else if rest.starts_with("True") {
+            (HeaderValue::Boolean(true), 4)
+        } else if rest.starts_with("False") {
+            (HeaderValue::Boolean(false), 5)
+        } else if rest.starts_with('(') {
+            let end = rest.find(')').ok_or_else(|| header_error(header))?;
+            let dimensions = rest[1..end]
+                .split(',')
+                .map(|dimension| dimension.trim())
+                .filter(|dimension| !dimension.is_empty())
+                .map(|dimension| dimension.trim_end_matches('L').parse::<usize>())
+                .collect::<Result<Vec<usize>, _>>()
+                .map_err(|_| header_error(header))?;
+            (HeaderValue::Tuple(dimensions), end + 1)
+        } else {
+            return Err(header_error(header));
+        };
+        entries.push((key, value));
+
+        rest = rest[length..].trim_start();
+        if let Some(next) = rest.strip_prefix(',') {
+            rest = next.trim_start();
+        } else if !rest.is_empty() {
+            return Err(header_error(header));
+        }
+    }
+
+    Ok(entries)
+}
+
+fn parse_descr(descr: &str) -> Result<(NpyType, Endian), Error> {
+    let unsupported = || Error::Format(format!("Unsupported dtype '{}'", descr));
+    let mut characters = descr.chars();
+    let endian = match characters.next() {
+        Some('<') => Endian::Little,
+        Some('>') => Endian::Big,
+        Some('=') => Endian::native(),
+        _ => return Err(unsupported()),
+    };
+    let dtype = match characters.as_str() {
+        "f4" => NpyType::F32,
+        "f8" => NpyType::F64,
+        "i4" => NpyType::I32,
+        "i8" => NpyType::I64,
+        _ => return Err(unsupported()),
+    };
+    Ok((dtype, endian))
+}
+
+// * Reading
+
+fn read_array(mut reader: impl Read) -> Result<Array, Error> {
+    let mut magic = [0; 6];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(Error::Format("Not a .npy file".to_string()));
+    }
+
+    let mut version = [0; 2];
+    reader.read_exact(&mut version)?;
+    let header_length = match version[0] {
+        1 => {
+            let mut length = [0; 2];
+            reader.read_exact(&mut length)?;
+            u16::from_le_bytes(length) as usize
+        }
+        2 | 3 => {
+            let mut length = [0; 4];
+            reader.read_exact(&mut length)?;
+            u32::from_le_bytes(length) as usize
+        }
+        major => {
+            return Err(Error::Format(format!(
+                "Unsupported .npy version {}.{}",
+                major, version[1]
+            )))
+        }
+    };
+    let mut header = vec![0; header_length];
+    reader.read_exact(&mut header)?;
+    let header = String::from_utf8(header)
+        .map_err(|_| Error::Format("Invalid .npy header encoding".to_string()))?;
+
+    // * Header entries
+    let mut descr = None;
+    let mut fortran_order = None;
+    let mut shape = None;
+    for (key, value) in parse_header(&header)? {
+        match (key.as_str(), value) {
+            ("descr", HeaderValue::Text(text)) => descr = Some(parse_descr(&text)?),
+            ("fortran_order", HeaderValue::Boolean(value)) => fortran_order = Some(value),
+            ("shape", HeaderValue::Tuple(value)) => shape = Some(value),
+            _ => return Err(header_error(&header)),
+        }
+    }
+    let ((dtype, endian), fortran_order, shape) = match (descr, fortran_order, shape) {
+        (Some(descr), Some(fortran_order), Some(shape)) => (descr, fortran_order, shape),
+        _ => return Err(header_error(&header)),
+    };
+    if shape.len() > 2 {
+        return Err(Error::Format(format!(
+            "Unsupported rank {} for shape {:?}, expected at most 2",
+            shape.len(),
+            shape
+        )));
+    }
+
+    // * Values
+    // The shape comes from the file, the data is read without trusting it for the allocation
+    let length = shape
+        .iter()
+        .try_fold(dtype.size(), |length, size| length.checked_mul(*size))
+        .ok_or_else(|| Error::Format(format!("Invalid shape {:?}", shape)))?;
+    let mut bytes = vec![];
+    reader.take(length as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != length {
+        return Err(Error::Format(format!(
+            "Expected {} bytes of data for shape {:?}, got {}",
+            length,
+            shape,
+            bytes.len()
+        )));
+    }
+    let values = bytes
+        .chunks_exact(dtype.size())
+        .map(|chunk| decode(chunk, dtype, endian))
+        .collect();
+
+    Ok(Array {
+        shape,
+        fortran_order,
+        values,
+    })
+}
+
+fn decode(chunk: &[u8], dtype: NpyType, endian: Endian) -> f64 {
+    macro_rules! value {
+        ($type:ty) => {{
+            let bytes = chunk.try_into().unwrap();
+            match endian {
+                Endian::Little => <$type>::from_le_bytes(bytes) as f64,
+                Endian::Big => <$type>::from_be_bytes(bytes) as f64,
+            }
+        }};
+    }
+    match dtype {
+        NpyType::F32 => value!(f32),
+        NpyType::F64 => value!(f64),
+        NpyType::I32 => value!(i32),
+        NpyType::I64 => value!(i64),
+    }
+}
+
+// Read a matrix from a `.npy` file
+// One-dimensional arrays are read as a single row and scalars as a 1x1 matrix
+pub fn read_npy_matrix<R: Read>(reader: R) -> Result<Matrix, Error> {
+    let array = read_array(reader)?;
+    let [rows, cols] = match array.shape.as_slice() {
+        [] => [1, 1],
+        [size] => [1, *size],
+        [rows, cols] => [*rows, *cols],
+        _ => unreachable!(),
+    };
+
+    // The data may be empty while the shape still has many rows to allocate
+    super::check_shape([rows, cols])?;
+    let mut matrix = Matrix::new([rows, cols]);
+    for (index, value) in array.values.into_iter().enumerate() {
+        if array.fortran_order {
+            matrix[index % rows][index / rows] = value;
+        } else {
+            matrix[index / cols][index % cols] = value;
+        }
+    }
+    Ok(matrix)
+}
+
+// Read a vector from a `.npy` file
+// Two-dimensional arrays are accepted if they have a single row or a single column
+pub fn read_npy_vector<R: Read>(reader: R) -> Result<Vector, Error> {
+    let array = read_array(reader)?;
+    match array.shape.as_slice() {
+        [] | [_] => Ok(Vector::from(array.values)),
+        [rows, cols] if *rows <= 1 || *cols == 1 => Ok(Vector::from(array.values)),
+        shape => Err(Error::Format(format!(
+            "Expected a single row or column, got shape {:?}",
+            shape
+        ))),
+    }
+}
+
+// * Writing
+
+fn encode(value: f64, options: &NpyOptions) -> Result<Vec<u8>, Error> {
+    macro_rules! bytes {
+        ($value:expr) => {
+            match options.endian {
+                Endian::Little => $value.to_le_bytes().to_vec(),
+                Endian::Big => $value.to_be_bytes().to_vec(),
+            }
+        };
+    }
+    macro_rules! integer {
+        ($type:ty) => {{
+            if value.fract() != 0. || value < <$type>::MIN as f64 || value > <$type>::MAX as f64 {
+                return Err(Error::Format(format!(
+                    "Value {} can't be written as {}",
+                    value,
+                    stringify!($type)
+                )));
+            }
+            bytes!(value as $type)
+        }};
+    }
+    Ok(match options.dtype {
+        NpyType::F32 => bytes!(value as f32),
+        NpyType::F64 => bytes!(value),
+        NpyType::I32 => integer!(i32),
+        NpyType::I64 => integer!(i64),
+    })
+}
+
+fn write_array(
+    mut writer: impl Write,
+    shape: &[usize],
+    values: impl Iterator<Item = f64>,
+    options: &NpyOptions,
+) -> Result<(), Error> {
+    let endian = match options.endian {
+        Endian::Little => '<',
+        Endian::Big => '>',
+    };
+    let shape = match shape {
+        [size] => format!("({},)", size),
+        shape => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|dimension| dimension.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '{}{}', 'fortran_order': {}, 'shape': {}, }}",
+        endian,
+        options.dtype.code(),
+        if options.fortran_order {
+            "True"
+        } else {
+            "False"
+        },
+        shape
+    );
+
+    // * Version 1 stores the header length on 2 bytes, version 2 on 4 bytes
+    let version: u8 = if header.len() + ALIGNMENT > u16::MAX as usize {
+        2
+    } else {
+        1
+    };
+    let preamble = MAGIC.len() + 2 + if version == 1 { 2 } else { 4 };
+    let padding = ALIGNMENT - (preamble + header.len() + 1) % ALIGNMENT;
+    header.push_str(&" ".repeat(padding % ALIGNMENT));
+    header.push('\n');
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[version, 0])?;
+    if version == 1 {
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+    } else {
+        writer.write_all(&(header.len() as u32).to_le_bytes())?;
+    }
+    writer.write_all(header.as_bytes())?;
+    for value in values {
+        writer.write_all(&encode(value, options)?)?;
+    }
+    Ok(())
+}
+
+// Write a matrix as a two-dimensional array
+pub fn write_npy_matrix<W: Write>(
+    writer: W,
+    matrix: &Matrix,
+    options: &NpyOptions,
+) -> Result<(), Error> {
+    let [rows, cols] = matrix.shape();
+    if options.fortran_order {
+        let values = (0..cols).flat_map(|column| (0..rows).map(move |row| matrix[row][column]));
+        write_array(writer, &[rows, cols], values, options)
+    } else {
+        let values = matrix.all().iter().flatten().copied();
+        write_array(writer, &[rows, cols], values, options)
+    }
+}
+
+// Write a vector as a one-dimensional array
+pub fn write_npy_vector<W: Write>(
+    writer: W,
+    vector: &Vector,
+    options: &NpyOptions,
+) -> Result<(), Error> {
+    write_array(
+        writer,
+        &[vector.size()],
+        vector.all().iter().copied(),
+        options,
+    )
+}
+
+// Read a matrix from a `.npy` file
+pub fn load_npy_matrix<P: AsRef<Path>>(path: P) -> Result<Matrix, Error> {
+    read_npy_matrix(BufReader::new(File::open(path)?))
+}
+
+// Read a vector from a `.npy` file
+pub fn load_npy_vector<P: AsRef<Path>>(path: P) -> Result<Vector, Error> {
+    read_npy_vector(BufReader::new(File::open(path)?))
+}
+
+// Write a matrix to a `.npy` file
+pub fn save_npy_matrix<P: AsRef<Path>>(
+    path: P,
+    matrix: &Matrix,
+    options: &NpyOptions,
+) -> Result<(), Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_npy_matrix(&mut writer, matrix, options)?;
+    writer.flush()?;
+    Ok(())
+}
+
+// Write a vector to a `.npy` file
+pub fn save_npy_vector<P: AsRef<Path>>(
+    path: P,
+    vector: &Vector,
+    options: &NpyOptions,
+) -> Result<(), Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_npy_vector(&mut writer, vector, options)?;
+    writer.flush()?;
+    Ok(())
+}
@@ -1,6 +1,27 @@
+pub mod bigint;
+mod block;
 pub mod cosine;
 pub mod cross_product;
+mod display;
+pub mod export;
+mod functions;
+pub mod gf2;
+mod integer;
+pub mod io;
 pub mod linear_combination;
 pub mod linear_interpolation;
 pub mod matrix;
+pub mod modular;
+pub mod parse;
+pub mod permutation;
+mod properties;
+pub mod rational;
+pub mod reduction;
+pub mod regression;
+pub mod scalar;
+pub mod semiring;
+#[cfg(feature = "serde")]
+mod serialize;
+pub mod stats;
+pub mod trace;
 pub mod vector;
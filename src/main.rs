@@ -1,11 +1,9 @@
-use matrix::matrix::Matrix;
+use std::process::ExitCode;
 
-fn main() {
-    let projection = Matrix::projection(90., 1., 1., 50.);
-    for row in 0..4 {
-        println!(
-            "{:.1}, {:.1}, {:.1}, {:.1}",
-            projection[row][0], projection[row][1], projection[row][2], projection[row][3]
-        );
-    }
+mod cli;
+mod repl;
+
+fn main() -> ExitCode {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    cli::run(&arguments)
 }
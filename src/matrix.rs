@@ -1,32 +1,52 @@
 use std::{
     f64::consts::PI,
     fmt::{self, Debug},
-    ops::{Add, Index, IndexMut, Mul, Sub},
-    slice::Iter,
+    io::{Read, Write},
+    iter::Flatten,
+    ops::{Add, Bound, Div, Index, IndexMut, Mul, Range, RangeBounds, Sub},
+    slice::{Iter, IterMut},
+    str::FromStr,
+    vec,
 };
 
-use crate::{linear_interpolation::Lerp, vector::Vector};
+use crate::{
+    display,
+    export::{self, ExportOptions},
+    io::{
+        self,
+        binary::{self, BinaryOptions},
+    },
+    linear_interpolation::Lerp,
+    parse::{self, ParseError},
+    permutation::Permutation,
+    scalar::{Field, Idempotent, Ring, Scalar, Semiring},
+    trace::{self, Operation, Step},
+    vector::Vector,
+};
 
 #[derive(Debug)]
-pub struct Matrix {
-    elements: Vec<Vec<f64>>,
+pub struct Matrix<K = f64> {
+    elements: Vec<Vec<K>>,
 }
 
-impl fmt::Display for Matrix {
+// Display the matrix as an aligned grid, honouring the precision and width of the formatter
+// -- `{:#}` displays the matrix on a single line instead
+impl<K: fmt::Display> fmt::Display for Matrix<K> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self.elements)
+        let rows: Vec<&[K]> = self.elements.iter().map(|row| row.as_slice()).collect();
+        display::write_rows(f, &rows)
     }
 }
 
-impl Default for Matrix {
+impl<K: Scalar> Default for Matrix<K> {
     fn default() -> Self {
-        Self::new([0, 0])
+        Self::zeros([0, 0])
     }
 }
 
 // * Clone
 
-impl Clone for Matrix {
+impl<K: Clone> Clone for Matrix<K> {
     fn clone_from(&mut self, source: &Self) {
         *self = source.clone()
     }
@@ -40,50 +60,50 @@ impl Clone for Matrix {
 
 // * Operations
 
-impl Add for Matrix {
-    type Output = Matrix;
+impl<K: Semiring> Add for Matrix<K> {
+    type Output = Matrix<K>;
 
     fn add(self, rhs: Self) -> Self::Output {
         if self.shape() == rhs.shape() {
-            let mut matrix = Matrix::new(self.shape());
+            let mut matrix = Matrix::zeros(self.shape());
             for x in 0..self.shape()[0] {
                 for y in 0..self.shape()[1] {
-                    matrix[x][y] = self[x][y] + rhs[x][y];
+                    matrix[x][y] = self[x][y].clone() + rhs[x][y].clone();
                 }
             }
             matrix
         } else {
-            Matrix::new([0, 0])
+            Matrix::zeros([0, 0])
         }
     }
 }
 
-impl Sub for Matrix {
-    type Output = Matrix;
+impl<K: Ring> Sub for Matrix<K> {
+    type Output = Matrix<K>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         if self.shape() == rhs.shape() {
-            let mut matrix = Matrix::new(self.shape());
+            let mut matrix = Matrix::zeros(self.shape());
             for x in 0..self.shape()[0] {
                 for y in 0..self.shape()[1] {
-                    matrix[x][y] = self[x][y] - rhs[x][y];
+                    matrix[x][y] = self[x][y].clone() - rhs[x][y].clone();
                 }
             }
             matrix
         } else {
-            Matrix::new([0, 0])
+            Matrix::zeros([0, 0])
         }
     }
 }
 
-impl Mul<f64> for Matrix {
-    type Output = Matrix;
+impl<K: Semiring> Mul<K> for Matrix<K> {
+    type Output = Matrix<K>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        let mut matrix = Matrix::new(self.shape());
+    fn mul(self, rhs: K) -> Self::Output {
+        let mut matrix = Matrix::zeros(self.shape());
         for x in 0..self.shape()[0] {
             for y in 0..self.shape()[1] {
-                matrix[x][y] = self[x][y] * rhs;
+                matrix[x][y] = self[x][y].clone() * rhs.clone();
             }
         }
         matrix
@@ -92,68 +112,90 @@ impl Mul<f64> for Matrix {
 
 // *> From
 
-impl From<Vec<f64>> for Matrix {
-    fn from(vec: Vec<f64>) -> Self {
+impl<K: Scalar> From<Vec<K>> for Matrix<K> {
+    fn from(vec: Vec<K>) -> Self {
         Matrix {
             elements: vec![vec],
         }
     }
 }
 
-impl From<Vec<Vec<f64>>> for Matrix {
-    fn from(mat: Vec<Vec<f64>>) -> Matrix {
-        // let length = mat.len();
-        // if length == 0 || mat[0].len() == length {
-        //     return Ok(Matrix { elements: mat });
-        // }
-        // Err(())
+impl<K: Scalar> From<Vec<Vec<K>>> for Matrix<K> {
+    // The rows aren't validated, see `Matrix::try_from_rows`
+    fn from(mat: Vec<Vec<K>>) -> Matrix<K> {
         Matrix { elements: mat }
     }
 }
 
-impl<const N: usize> From<[f64; N]> for Matrix {
-    fn from(slice: [f64; N]) -> Self {
+impl<K: Scalar, const N: usize> From<[K; N]> for Matrix<K> {
+    fn from(slice: [K; N]) -> Self {
         Matrix {
             elements: vec![slice.to_vec()],
         }
     }
 }
 
-impl<const N: usize, const M: usize> From<[[f64; N]; M]> for Matrix {
-    fn from(mat: [[f64; N]; M]) -> Self {
+impl<K: Scalar, const N: usize, const M: usize> From<[[K; N]; M]> for Matrix<K> {
+    fn from(mat: [[K; N]; M]) -> Self {
         Matrix {
             elements: mat.iter().map(|slice| slice.to_vec()).collect(),
         }
     }
 }
 
-impl Index<usize> for Matrix {
-    type Output = Vec<f64>;
+// Parse a matrix written as `[1 2; 3 4]`, `[[1, 2], [3, 4]]` or as lines of values
+impl FromStr for Matrix {
+    type Err = ParseError;
 
-    fn index(&self, i: usize) -> &Vec<f64> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Matrix {
+            elements: parse::parse_rows(s)?,
+        })
+    }
+}
+
+// A single index gives a row as a slice, so the shape can't be changed through it
+impl<K> Index<usize> for Matrix<K> {
+    type Output = [K];
+
+    fn index(&self, i: usize) -> &[K] {
         &self.elements[i]
     }
 }
 
-impl IndexMut<usize> for Matrix {
-    fn index_mut(&mut self, i: usize) -> &mut Vec<f64> {
+impl<K> IndexMut<usize> for Matrix<K> {
+    fn index_mut(&mut self, i: usize) -> &mut [K] {
         &mut self.elements[i]
     }
 }
 
+impl<K> Index<(usize, usize)> for Matrix<K> {
+    type Output = K;
+
+    fn index(&self, (row, column): (usize, usize)) -> &K {
+        &self.elements[row][column]
+    }
+}
+
+impl<K> IndexMut<(usize, usize)> for Matrix<K> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut K {
+        &mut self.elements[row][column]
+    }
+}
+
 // *< From
 
 // *> Iterator
 
-pub struct ColumnIterator<'a> {
-    matrix: &'a Matrix,
+pub struct ColumnIterator<'a, K = f64> {
+    matrix: &'a Matrix<K>,
     shape: [usize; 2],
     current_row: usize,
     current_column: usize,
 }
 
-impl Iterator for ColumnIterator<'_> {
-    type Item = f64;
+impl<K: Clone> Iterator for ColumnIterator<'_, K> {
+    type Item = K;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.shape[0] == 0 || self.shape[1] == 0 {
@@ -173,10 +215,18 @@ impl Iterator for ColumnIterator<'_> {
             self.current_row += 1;
         }
 
-        Some(self.matrix[row][column])
+        Some(self.matrix[row][column].clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let [rows, cols] = self.shape;
+        let remaining = (rows * cols).saturating_sub(self.current_column * rows + self.current_row);
+        (remaining, Some(remaining))
     }
 }
 
+impl<K: Clone> ExactSizeIterator for ColumnIterator<'_, K> {}
+
 pub struct TupleIterator<'a> {
     matrix_a: &'a Matrix,
     matrix_b: &'a Matrix,
@@ -192,7 +242,7 @@ impl Iterator for TupleIterator<'_> {
         if self.shape[0] == 0 || self.shape[1] == 0 {
             return None;
         }
-        if self.current_row >= self.shape[1] {
+        if self.current_row >= self.shape[0] {
             return None;
         }
 
@@ -208,6 +258,240 @@ impl Iterator for TupleIterator<'_> {
 
         Some([self.matrix_a[row][column], self.matrix_b[row][column]])
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let [rows, cols] = self.shape;
+        let remaining = (rows * cols).saturating_sub(self.current_row * cols + self.current_column);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for TupleIterator<'_> {}
+
+// Elements of a matrix in row-major order, with their exact number
+pub struct Elements<I> {
+    inner: I,
+    remaining: usize,
+}
+
+impl<I: Iterator> Iterator for Elements<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.inner.next()?;
+        self.remaining -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for Elements<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let value = self.inner.next_back()?;
+        self.remaining -= 1;
+        Some(value)
+    }
+}
+
+impl<I: Iterator> ExactSizeIterator for Elements<I> {}
+
+// Elements of a matrix with their position, as ((row, column), value)
+// -- positions are tracked for each row, so they are also correct for rows of different sizes
+pub struct IndexedIter<'a, K> {
+    rows: Iter<'a, Vec<K>>,
+    row: usize,
+    values: Iter<'a, K>,
+    column: usize,
+    remaining: usize,
+}
+
+impl<'a, K> Iterator for IndexedIter<'a, K> {
+    type Item = ((usize, usize), &'a K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.values.next() {
+                let position = (self.row, self.column);
+                self.column += 1;
+                self.remaining -= 1;
+                return Some((position, value));
+            }
+            self.values = self.rows.next()?.iter();
+            // `row` starts at usize::MAX so the first row is numbered 0
+            self.row = self.row.wrapping_add(1);
+            self.column = 0;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K> ExactSizeIterator for IndexedIter<'_, K> {}
+
+// Each row of a matrix as a slice
+pub struct Rows<'a, K> {
+    rows: Iter<'a, Vec<K>>,
+}
+
+impl<'a, K> Iterator for Rows<'a, K> {
+    type Item = &'a [K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|row| row.as_slice())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<K> DoubleEndedIterator for Rows<'_, K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.rows.next_back().map(|row| row.as_slice())
+    }
+}
+
+impl<K> ExactSizeIterator for Rows<'_, K> {}
+
+// View of a single column of a matrix
+pub struct Column<'a, K> {
+    matrix: &'a Matrix<K>,
+    column: usize,
+}
+
+impl<'a, K> Column<'a, K> {
+    // Number of elements in the column, which is the number of rows of the matrix
+    pub fn len(&self) -> usize {
+        self.matrix.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, row: usize) -> Option<&'a K> {
+        self.matrix
+            .elements
+            .get(row)
+            .map(|values| &values[self.column])
+    }
+
+    pub fn iter(&self) -> ColumnValues<'a, K> {
+        ColumnValues {
+            rows: self.matrix.elements.iter(),
+            column: self.column,
+        }
+    }
+}
+
+impl<K> Index<usize> for Column<'_, K> {
+    type Output = K;
+
+    fn index(&self, row: usize) -> &K {
+        &self.matrix.elements[row][self.column]
+    }
+}
+
+impl<'a, K> IntoIterator for Column<'a, K> {
+    type Item = &'a K;
+    type IntoIter = ColumnValues<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Elements of a column, from the first row to the last
+pub struct ColumnValues<'a, K> {
+    rows: Iter<'a, Vec<K>>,
+    column: usize,
+}
+
+impl<'a, K> Iterator for ColumnValues<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|row| &row[self.column])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<K> DoubleEndedIterator for ColumnValues<'_, K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.rows.next_back().map(|row| &row[self.column])
+    }
+}
+
+impl<K> ExactSizeIterator for ColumnValues<'_, K> {}
+
+// Each column of a matrix as a view
+pub struct Cols<'a, K> {
+    matrix: &'a Matrix<K>,
+    columns: Range<usize>,
+}
+
+impl<'a, K> Iterator for Cols<'a, K> {
+    type Item = Column<'a, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.columns.next().map(|column| Column {
+            matrix: self.matrix,
+            column,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.columns.size_hint()
+    }
+}
+
+impl<K> DoubleEndedIterator for Cols<'_, K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.columns.next_back().map(|column| Column {
+            matrix: self.matrix,
+            column,
+        })
+    }
+}
+
+impl<K> ExactSizeIterator for Cols<'_, K> {}
+
+impl<K: Scalar> IntoIterator for Matrix<K> {
+    type Item = K;
+    type IntoIter = Elements<Flatten<vec::IntoIter<Vec<K>>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Elements {
+            remaining: self.element_count(),
+            inner: self.elements.into_iter().flatten(),
+        }
+    }
+}
+
+impl<'a, K: Scalar> IntoIterator for &'a Matrix<K> {
+    type Item = &'a K;
+    type IntoIter = Elements<Flatten<Iter<'a, Vec<K>>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: Scalar> IntoIterator for &'a mut Matrix<K> {
+    type Item = &'a mut K;
+    type IntoIter = Elements<Flatten<IterMut<'a, Vec<K>>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
 }
 
 // *< Iterator
@@ -233,26 +517,100 @@ impl Lerp for Matrix {
 
 // * Matrix
 
-impl Matrix {
-    pub fn new(shape: [usize; 2]) -> Matrix {
+// Range of indices in 0..length, None if it's reversed or goes out of bounds
+fn bounds(range: impl RangeBounds<usize>, length: usize) -> Option<Range<usize>> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end.checked_add(1)?,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => length,
+    };
+    (start <= end && end <= length).then_some(start..end)
+}
+
+impl<K: Scalar> Matrix<K> {
+    // Matrix of the given shape filled with zeros, for any scalar
+    pub fn zeros(shape: [usize; 2]) -> Matrix<K> {
+        Matrix {
+            elements: vec![vec![K::zero(); shape[1]]; shape[0]],
+        }
+    }
+
+    // Matrix from a list of rows, fails if they don't all have the same size
+    pub fn try_from_rows(rows: Vec<Vec<K>>) -> Result<Matrix<K>, String> {
+        if let Some(first) = rows.first() {
+            if let Some((index, row)) = rows
+                .iter()
+                .enumerate()
+                .find(|(_, row)| row.len() != first.len())
+            {
+                return Err(format!(
+                    "Invalid row {} of size {}, expected {}",
+                    index,
+                    row.len(),
+                    first.len()
+                ));
+            }
+        }
+        Ok(Matrix { elements: rows })
+    }
+
+    // Matrix where each element is the result of the function called with its row and column
+    pub fn from_fn(shape: [usize; 2], callback: impl Fn(usize, usize) -> K) -> Matrix<K> {
         Matrix {
-            elements: vec![vec![0.; shape[1]]; shape[0]],
+            elements: (0..shape[0])
+                .map(|row| (0..shape[1]).map(|column| callback(row, column)).collect())
+                .collect(),
         }
     }
 
+    // Square matrix with the vector on its diagonal
+    pub fn from_diagonal(diagonal: &Vector<K>) -> Matrix<K> {
+        let mut matrix = Matrix::zeros([diagonal.size(), diagonal.size()]);
+        for (index, value) in diagonal.iter().enumerate() {
+            matrix[index][index] = value.clone();
+        }
+        matrix
+    }
+
+    // Matrix with each of the vectors as a column, fails if they don't all have the same size
+    pub fn from_columns(columns: &[Vector<K>]) -> Result<Matrix<K>, String> {
+        let Some(first) = columns.first() else {
+            return Ok(Matrix::zeros([0, 0]));
+        };
+        if let Some((index, column)) = columns
+            .iter()
+            .enumerate()
+            .find(|(_, column)| column.size() != first.size())
+        {
+            return Err(format!(
+                "Invalid column {} of size {}, expected {}",
+                index,
+                column.size(),
+                first.size()
+            ));
+        }
+        Ok(Matrix::from_fn(
+            [first.size(), columns.len()],
+            |row, column| columns[column][row].clone(),
+        ))
+    }
+
     // * Utility functions
 
     // Return the identity matrix of the given size
     #[allow(dead_code)]
-    pub fn identity(size: usize, value: f64) -> Matrix {
+    pub fn identity(size: usize, value: K) -> Matrix<K> {
         if size == 0 {
             return Matrix::default();
         }
-        let mut matrix = Matrix {
-            elements: vec![vec![0.; size]; size],
-        };
+        let mut matrix = Matrix::zeros([size, size]);
         for diagonal in 0..size {
-            matrix[diagonal][diagonal] = value
+            matrix[diagonal][diagonal] = value.clone()
         }
         matrix
     }
@@ -268,19 +626,64 @@ impl Matrix {
     }
 
     #[allow(dead_code)]
-    pub fn all(&self) -> &Vec<Vec<f64>> {
+    pub fn all(&self) -> &[Vec<K>] {
         &self.elements
     }
 
+    // Element at the given position, None if it's out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<&K> {
+        self.elements.get(row)?.get(column)
+    }
+
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut K> {
+        self.elements.get_mut(row)?.get_mut(column)
+    }
+
+    // Same elements in row-major order with a new shape, which must have the same number of elements
+    pub fn reshape(&self, shape: [usize; 2]) -> Result<Matrix<K>, String> {
+        let [rows, cols] = self.shape();
+        // An overflowing size is never the one of the matrix
+        if shape[0].checked_mul(shape[1]) != Some(rows * cols) {
+            return Err(format!("Can't reshape {:?} to {:?}", [rows, cols], shape));
+        }
+        let elements: Vec<&K> = self.iter().collect();
+        Ok(Matrix::from_fn(shape, |row, column| {
+            elements[row * shape[1] + column].clone()
+        }))
+    }
+
+    // Elements in row-major order
+    pub fn flatten(&self) -> Vector<K> {
+        self.iter().cloned().collect()
+    }
+
+    // Copy of the sub-matrix in the given ranges of rows and columns, e.g. `matrix.slice(1..3, ..)`
+    pub fn slice(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> Result<Matrix<K>, String> {
+        let shape = self.shape();
+        let (Some(rows), Some(cols)) = (bounds(rows, shape[0]), bounds(cols, shape[1])) else {
+            return Err(format!("Invalid ranges for shape {:?}", shape));
+        };
+        Ok(Matrix {
+            elements: self.elements[rows]
+                .iter()
+                .map(|row| row[cols.clone()].to_vec())
+                .collect(),
+        })
+    }
+
     // Create an iterator in the direction of the rows of the matrix
     #[allow(dead_code)]
-    pub fn iter_rows(&self) -> Iter<'_, Vec<f64>> {
-        self.elements.iter()
+    pub fn iter_rows(&self) -> Rows<'_, K> {
+        self.rows()
     }
 
     // Create an iterator in the direction of the columns of the matrix
     #[allow(dead_code)]
-    pub fn iter_cols(&self) -> ColumnIterator {
+    pub fn iter_cols(&self) -> ColumnIterator<'_, K> {
         ColumnIterator {
             matrix: self,
             shape: self.shape(),
@@ -289,6 +692,295 @@ impl Matrix {
         }
     }
 
+    // Number of elements, counted in each row since rows of different sizes can be built with `From`
+    fn element_count(&self) -> usize {
+        self.elements.iter().map(|row| row.len()).sum()
+    }
+
+    // Elements in row-major order
+    pub fn iter(&self) -> Elements<Flatten<Iter<'_, Vec<K>>>> {
+        Elements {
+            remaining: self.element_count(),
+            inner: self.elements.iter().flatten(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> Elements<Flatten<IterMut<'_, Vec<K>>>> {
+        Elements {
+            remaining: self.element_count(),
+            inner: self.elements.iter_mut().flatten(),
+        }
+    }
+
+    // Elements in row-major order with their position, as ((row, column), value)
+    pub fn indexed_iter(&self) -> IndexedIter<'_, K> {
+        IndexedIter {
+            rows: self.elements.iter(),
+            row: usize::MAX,
+            values: [].iter(),
+            column: 0,
+            remaining: self.element_count(),
+        }
+    }
+
+    pub fn rows(&self) -> Rows<'_, K> {
+        Rows {
+            rows: self.elements.iter(),
+        }
+    }
+
+    pub fn cols(&self) -> Cols<'_, K> {
+        Cols {
+            matrix: self,
+            columns: 0..self.shape()[1],
+        }
+    }
+
+    pub(crate) fn row_slices(&self) -> Vec<&[K]> {
+        self.elements.iter().map(|row| row.as_slice()).collect()
+    }
+
+    pub fn transpose(&self) -> Matrix<K> {
+        let [rows, cols] = self.shape();
+        let mut result = Matrix::zeros([cols, rows]);
+        for row in 0..rows {
+            for column in 0..cols {
+                result[column][row] = self[row][column].clone();
+            }
+        }
+        result
+    }
+
+    // * Rows and columns
+
+    // Insert a row before the given index, an index equal to the number of rows appends it
+    pub fn insert_row(&mut self, index: usize, row: Vec<K>) -> Result<(), String> {
+        let [rows, cols] = self.shape();
+        if index > rows {
+            return Err(format!(
+                "Row {} is out of bounds for shape {:?}",
+                index,
+                [rows, cols]
+            ));
+        }
+        if rows > 0 && row.len() != cols {
+            return Err(format!(
+                "Invalid row of size {} for shape {:?}",
+                row.len(),
+                [rows, cols]
+            ));
+        }
+        self.elements.insert(index, row);
+        Ok(())
+    }
+
+    // Insert a column before the given index, an index equal to the number of columns appends it
+    pub fn insert_col(&mut self, index: usize, column: Vec<K>) -> Result<(), String> {
+        let [rows, cols] = self.shape();
+        if index > cols {
+            return Err(format!(
+                "Column {} is out of bounds for shape {:?}",
+                index,
+                [rows, cols]
+            ));
+        }
+        if rows == 0 {
+            self.elements = column.into_iter().map(|value| vec![value]).collect();
+            return Ok(());
+        }
+        if column.len() != rows {
+            return Err(format!(
+                "Invalid column of size {} for shape {:?}",
+                column.len(),
+                [rows, cols]
+            ));
+        }
+        for (row, value) in self.elements.iter_mut().zip(column) {
+            row.insert(index, value);
+        }
+        Ok(())
+    }
+
+    // Remove and return the row at the given index
+    pub fn remove_row(&mut self, index: usize) -> Result<Vec<K>, String> {
+        if index >= self.shape()[0] {
+            return Err(format!(
+                "Row {} is out of bounds for shape {:?}",
+                index,
+                self.shape()
+            ));
+        }
+        Ok(self.elements.remove(index))
+    }
+
+    // Remove and return the column at the given index
+    pub fn remove_col(&mut self, index: usize) -> Result<Vec<K>, String> {
+        if index >= self.shape()[1] {
+            return Err(format!(
+                "Column {} is out of bounds for shape {:?}",
+                index,
+                self.shape()
+            ));
+        }
+        Ok(self
+            .elements
+            .iter_mut()
+            .map(|row| row.remove(index))
+            .collect())
+    }
+
+    // Panics if one of the indices is out of bounds, as an index access would
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.elements.swap(a, b);
+    }
+
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        let cols = self.shape()[1];
+        assert!(a < cols && b < cols, "Index out of bounds");
+        for row in self.elements.iter_mut() {
+            row.swap(a, b);
+        }
+    }
+
+    // Row i of the result is the row `permutation.indices()[i]`, the same as P * A
+    pub fn permute_rows(&self, permutation: &Permutation) -> Result<Matrix<K>, String> {
+        if permutation.size() != self.shape()[0] {
+            return Err(format!(
+                "Invalid permutation of size {} for shape {:?}",
+                permutation.size(),
+                self.shape()
+            ));
+        }
+        self.select_rows(permutation.indices())
+    }
+
+    // New matrix with the given rows in order, rows can be repeated
+    pub fn select_rows(&self, indices: &[usize]) -> Result<Matrix<K>, String> {
+        let shape = self.shape();
+        if let Some(index) = indices.iter().find(|index| **index >= shape[0]) {
+            return Err(format!(
+                "Row {} is out of bounds for shape {:?}",
+                index, shape
+            ));
+        }
+        Ok(Matrix {
+            elements: indices
+                .iter()
+                .map(|index| self.elements[*index].clone())
+                .collect(),
+        })
+    }
+
+    // New matrix with the given columns in order, columns can be repeated
+    pub fn select_cols(&self, indices: &[usize]) -> Result<Matrix<K>, String> {
+        let shape = self.shape();
+        if let Some(index) = indices.iter().find(|index| **index >= shape[1]) {
+            return Err(format!(
+                "Column {} is out of bounds for shape {:?}",
+                index, shape
+            ));
+        }
+        Ok(Matrix {
+            elements: self
+                .elements
+                .iter()
+                .map(|row| indices.iter().map(|index| row[*index].clone()).collect())
+                .collect(),
+        })
+    }
+
+    // Truncate or extend the matrix to the given shape, new elements are set to `fill`
+    pub fn resize(&mut self, shape: [usize; 2], fill: K) {
+        self.elements.resize(shape[0], vec![fill.clone(); shape[1]]);
+        for row in self.elements.iter_mut() {
+            row.resize(shape[1], fill.clone());
+        }
+    }
+
+    // * Closures
+
+    // New matrix with the function applied on each of the elements
+    pub fn map<L>(&self, callback: impl Fn(&K) -> L) -> Matrix<L> {
+        Matrix {
+            elements: self
+                .elements
+                .iter()
+                .map(|row| row.iter().map(&callback).collect())
+                .collect(),
+        }
+    }
+
+    // Same as `map`, with the row and the column of each element
+    pub fn map_indexed<L>(&self, callback: impl Fn(usize, usize, &K) -> L) -> Matrix<L> {
+        Matrix {
+            elements: self
+                .elements
+                .iter()
+                .enumerate()
+                .map(|(row, values)| {
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(column, value)| callback(row, column, value))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    // New matrix with the function applied on the elements at the same position in both matrices
+    pub fn zip_map<M: Scalar, L>(
+        &self,
+        other: &Matrix<M>,
+        callback: impl Fn(&K, &M) -> L,
+    ) -> Result<Matrix<L>, String> {
+        if self.shape() != other.shape() {
+            return Err(format!(
+                "Invalid shapes {:?} and {:?}",
+                self.shape(),
+                other.shape()
+            ));
+        }
+        Ok(Matrix {
+            elements: self
+                .elements
+                .iter()
+                .zip(other.elements.iter())
+                .map(|(a, b)| {
+                    a.iter()
+                        .zip(b.iter())
+                        .map(|(a, b)| callback(a, b))
+                        .collect()
+                })
+                .collect(),
+        })
+    }
+
+    // Update each of the elements in place
+    pub fn apply_mut(&mut self, mut callback: impl FnMut(&mut K)) {
+        for value in self.elements.iter_mut().flatten() {
+            callback(value);
+        }
+    }
+
+    // Accumulate the elements, row by row
+    pub fn fold<B>(&self, init: B, callback: impl FnMut(B, &K) -> B) -> B {
+        self.elements.iter().flatten().fold(init, callback)
+    }
+
+    // Call the function on each of the elements, row by row
+    pub fn for_each(&self, callback: impl FnMut(&K)) {
+        self.elements.iter().flatten().for_each(callback);
+    }
+}
+
+impl Matrix {
+    pub fn new(shape: [usize; 2]) -> Matrix {
+        Matrix::zeros(shape)
+    }
+
+    // * Utility functions
+
     // Create an iterator with the value of two matrices
     #[allow(dead_code)]
     pub fn iter_tuple<'a>(a: &'a Matrix, b: &'a Matrix) -> Result<TupleIterator<'a>, String> {
@@ -312,45 +1004,57 @@ impl Matrix {
         Matrix::iter_tuple(self, b)
     }
 
-    // Apply a function on each of the elements of the matrix and return a new matrix with the function applied
-    #[allow(dead_code)]
-    pub fn map<'a>(
-        a: &'a Matrix,
-        b: &'a Matrix,
-        callback: fn(a: f64, b: f64) -> f64,
-    ) -> Result<Matrix, String> {
-        let a_shape = a.shape();
-        if a_shape != b.shape() {
-            return Err(format!("Invalid shapes {:?} and {:?}", a_shape, b.shape()));
-        }
+    // * Export functions
 
-        let mut new_matrix = Matrix::new(a_shape);
-        for row in 0..a_shape[0] {
-            for column in 0..a_shape[1] {
-                new_matrix[row][column] = callback(a[row][column], b[row][column]);
-            }
-        }
+    // LaTeX `bmatrix` environment
+    pub fn to_latex(&self, options: &ExportOptions) -> String {
+        export::latex(&self.row_slices(), options)
+    }
 
-        Ok(new_matrix)
+    // Markdown table, with the index of each column as the header
+    pub fn to_markdown(&self, options: &ExportOptions) -> String {
+        export::markdown(&self.row_slices(), options)
     }
 
-    // * Subject functions
+    // MATLAB literal, as `[a b; c d]`
+    pub fn to_matlab(&self, options: &ExportOptions) -> String {
+        export::matlab(&self.row_slices(), options)
+    }
 
-    pub fn add(&mut self, b: &Matrix) {
-        let shape = self.shape();
-        if shape != b.shape() {
-            // return Err(format!("Invalid shapes {:?} and {:?}", shape, b.shape()));
-            return;
-        }
+    // NumPy literal, as `np.array([[a, b], [c, d]], dtype=float)`
+    pub fn to_numpy(&self, options: &ExportOptions) -> String {
+        export::numpy_matrix(&self.row_slices(), options)
+    }
 
-        for row in 0..shape[0] {
-            for column in 0..shape[1] {
-                self[row][column] += b[row][column];
-            }
-        }
+    // * Binary serialization
+
+    // Write the matrix as a self-describing binary record
+    pub fn write_to<W: Write>(&self, writer: W, options: &BinaryOptions) -> Result<(), io::Error> {
+        binary::write_matrix(writer, self, options)
     }
 
-    pub fn sub(&mut self, b: &Matrix) {
+    // Read a matrix written by `write_to`
+    pub fn read_from<R: Read>(reader: R) -> Result<Matrix, io::Error> {
+        binary::read_matrix(reader)
+    }
+
+    pub fn projection(fov: f64, ratio: f64, near: f64, far: f64) -> Matrix {
+        let s = 1. / (f64::tan((fov / 2.) * (PI / 180.)));
+        let x_scale = if ratio < 1. { ratio } else { 1. };
+        let y_scale = if ratio > 1. { 1. / ratio } else { 1. };
+        Matrix::from([
+            [s * x_scale, 0., 0., 0.],
+            [0., s * y_scale, 0., 0.],
+            [0., 0., -far / (far - near), -1.],
+            [0., 0., -((far * near) / (far - near)), 0.],
+        ])
+    }
+}
+
+impl<K: Semiring> Matrix<K> {
+    // * Subject functions
+
+    pub fn add(&mut self, b: &Matrix<K>) {
         let shape = self.shape();
         if shape != b.shape() {
             // return Err(format!("Invalid shapes {:?} and {:?}", shape, b.shape()));
@@ -359,31 +1063,31 @@ impl Matrix {
 
         for row in 0..shape[0] {
             for column in 0..shape[1] {
-                self[row][column] -= b[row][column];
+                self[row][column] = self[row][column].clone() + b[row][column].clone();
             }
         }
     }
 
-    pub fn scl(&mut self, value: f64) {
+    pub fn scl(&mut self, value: K) {
         let [rows, cols] = self.shape();
         for row in 0..rows {
             for column in 0..cols {
-                self[row][column] *= value;
+                self[row][column] = self[row][column].clone() * value.clone();
             }
         }
     }
 
-    pub fn mul_vec(&self, vector: &Vector) -> Vector {
+    pub fn mul_vec(&self, vector: &Vector<K>) -> Vector<K> {
         let [rows, cols] = self.shape();
         if cols != vector.size() {
-            return Vector::new(0);
+            return Vector::zeros(0);
         }
 
-        let mut result = Vector::new(rows);
+        let mut result = Vector::zeros(rows);
         for row in 0..rows {
-            let mut value = 0.;
+            let mut value = K::zero();
             for column in 0..cols {
-                value += self[row][column] * vector[column];
+                value = value + self[row][column].clone() * vector[column].clone();
             }
             result[row] = value;
         }
@@ -391,19 +1095,20 @@ impl Matrix {
         result
     }
 
-    pub fn mul_mat(&self, matrix: &Matrix) -> Matrix {
+    pub fn mul_mat(&self, matrix: &Matrix<K>) -> Matrix<K> {
         let self_shape = self.shape();
         let other_shape = matrix.shape();
-        if self_shape[0] != other_shape[1] || self_shape[1] != other_shape[0] {
-            return Matrix::new([0, 0]);
+        if self_shape[1] != other_shape[0] {
+            return Matrix::zeros([0, 0]);
         }
 
-        let mut result = Matrix::new([self_shape[0], other_shape[1]]);
+        let mut result = Matrix::zeros([self_shape[0], other_shape[1]]);
         for row in 0..self_shape[0] {
             for result_column in 0..other_shape[1] {
-                let mut value = 0.;
+                let mut value = K::zero();
                 for column in 0..self_shape[1] {
-                    value += self[row][column] * matrix[column][result_column];
+                    value =
+                        value + self[row][column].clone() * matrix[column][result_column].clone();
                 }
                 result[row][result_column] = value;
             }
@@ -412,32 +1117,154 @@ impl Matrix {
         result
     }
 
-    pub fn trace(&self) -> f64 {
+    pub fn trace(&self) -> K {
         let [rows, cols] = self.shape();
         if rows != cols {
-            return 0.;
+            return K::zero();
         }
 
-        let mut result = 0.;
+        let mut result = K::zero();
         for i in 0..rows {
-            result += self[i][i];
+            result = result + self[i][i].clone();
         }
 
         result
     }
+}
 
-    pub fn transpose(&self) -> Matrix {
+impl<K: Idempotent> Matrix<K> {
+    // Reflexive and transitive closure I + A + A^2 + ..., computed by squaring (I + A)
+    // -- reachability for Boolean, shortest paths for MinPlus and longest paths for MaxPlus
+    // An empty matrix is returned if the matrix isn't square
+    pub fn closure(&self) -> Matrix<K> {
         let [rows, cols] = self.shape();
-        let mut result = Matrix::new([cols, rows]);
-        for row in 0..rows {
-            for column in 0..cols {
-                result[column][row] = self[row][column];
+        if rows != cols {
+            return Matrix::zeros([0, 0]);
+        }
+
+        let mut result = self.clone() + Matrix::identity(rows, K::one());
+        // Paths use at most n - 1 edges, so log2(n) squarings are enough
+        let mut length = 1;
+        while length < rows.saturating_sub(1) {
+            let squared = result.mul_mat(&result);
+            if squared.all() == result.all() {
+                break;
             }
+            result = squared;
+            length *= 2;
         }
         result
     }
+}
+
+impl<K: Ring> Matrix<K> {
+    pub fn sub(&mut self, b: &Matrix<K>) {
+        let shape = self.shape();
+        if shape != b.shape() {
+            // return Err(format!("Invalid shapes {:?} and {:?}", shape, b.shape()));
+            return;
+        }
+
+        for row in 0..shape[0] {
+            for column in 0..shape[1] {
+                self[row][column] = self[row][column].clone() - b[row][column].clone();
+            }
+        }
+    }
+}
+
+// The Bareiss algorithm only uses exact divisions, the scalar doesn't need to be a field
+impl<K: Ring + Div<Output = K>> Matrix<K> {
+    // Use the Bareiss algorithm to find the determinant
+    pub fn determinant(&self) -> K {
+        self.determinant_traced(None)
+    }
+
+    // Determinant, with each of the row operations of the Bareiss algorithm
+    pub fn determinant_steps(&self) -> (K, Vec<Step<K>>) {
+        let mut steps = vec![];
+        let result = self.determinant_traced(Some(&mut steps));
+        (result, steps)
+    }
+
+    fn determinant_traced(&self, mut steps: Option<&mut Vec<Step<K>>>) -> K {
+        let [rows, cols] = self.shape();
+        if rows != cols {
+            return K::zero();
+        }
+
+        if rows == 1 {
+            return self[0][0].clone();
+        }
+
+        let mut sign = K::one();
+        let mut matrix = self.clone();
+        for k in 0..(rows - 1) {
+            // Pivot row swap if needed
+            if matrix[k][k].is_zero() {
+                let mut m = k + 1;
+                while m < rows {
+                    if !matrix[m][k].is_zero() {
+                        matrix.elements.swap(m, k);
+                        sign = -sign;
+                        trace::record(&mut steps, Operation::Swap(m, k), || matrix.clone());
+                        break;
+                    }
+                    m += 1;
+                }
+                if m == rows {
+                    return K::zero();
+                }
+            }
+
+            // Formula
+            let divisor = if k != 0 {
+                matrix[k - 1][k - 1].clone()
+            } else {
+                K::one()
+            };
+            for i in (k + 1)..rows {
+                let factor = matrix[i][k].clone();
+                for j in (k + 1)..cols {
+                    matrix[i][j] = matrix[k][k].clone() * matrix[i][j].clone()
+                        - matrix[i][k].clone() * matrix[k][j].clone();
+                    if k != 0 {
+                        matrix[i][j] = matrix[i][j].clone() / matrix[k - 1][k - 1].clone();
+                    }
+                }
+                // The column of the pivot is never read again, it's only cleared for the steps
+                matrix[i][k] = K::zero();
+                trace::record(
+                    &mut steps,
+                    Operation::FractionFree {
+                        target: i,
+                        pivot: k,
+                        scale: matrix[k][k].clone(),
+                        factor,
+                        divisor: divisor.clone(),
+                    },
+                    || matrix.clone(),
+                );
+            }
+        }
+
+        sign * matrix[rows - 1][rows - 1].clone()
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    pub fn row_echelon(&self) -> Matrix<K> {
+        self.row_echelon_traced(None)
+    }
+
+    // Row echelon form, with each of the row operations used to find it
+    pub fn row_echelon_steps(&self) -> (Matrix<K>, Vec<Step<K>>) {
+        let mut steps = vec![];
+        let result = self.row_echelon_traced(Some(&mut steps));
+        (result, steps)
+    }
 
-    pub fn row_echelon(&self) -> Matrix {
+    fn row_echelon_traced(&self, mut steps: Option<&mut Vec<Step<K>>>) -> Matrix<K> {
         let [rows, cols] = self.shape();
         let mut lead = 0;
 
@@ -447,7 +1274,7 @@ impl Matrix {
                 return result;
             }
             let mut i = r;
-            while result[i][lead] == 0. {
+            while result[i][lead].is_zero() {
                 i += 1;
                 if rows == i {
                     i = r;
@@ -459,19 +1286,32 @@ impl Matrix {
             }
 
             result.elements.swap(i, r);
+            if i != r {
+                trace::record(&mut steps, Operation::Swap(i, r), || result.clone());
+            }
 
-            let val = result[r][lead];
+            let val = result[r][lead].clone();
             for j in 0..cols {
-                result[r][j] /= val;
+                result[r][j] = result[r][j].clone() / val.clone();
+            }
+            if val != K::one() {
+                trace::record(&mut steps, Operation::Scale(r, K::one() / val), || {
+                    result.clone()
+                });
             }
 
             for i in 0..rows {
                 if i == r {
                     continue;
                 }
-                let val = result[i][lead];
+                let val = result[i][lead].clone();
                 for j in 0..cols {
-                    result[i][j] -= val * result[r][j];
+                    result[i][j] = result[i][j].clone() - val.clone() * result[r][j].clone();
+                }
+                if !val.is_zero() {
+                    trace::record(&mut steps, Operation::AddMultiple(i, r, -val), || {
+                        result.clone()
+                    });
                 }
             }
 
@@ -481,64 +1321,35 @@ impl Matrix {
         result
     }
 
-    pub fn reduced_row_echelon(&self) -> Matrix {
+    pub fn reduced_row_echelon(&self) -> Matrix<K> {
         self.row_echelon()
     }
 
-    // Use the Bareiss algorithm to find the determinant
-    pub fn determinant(&self) -> f64 {
-        let [rows, cols] = self.shape();
-        if rows != cols {
-            return 0.;
-        }
-
-        if rows == 1 {
-            return self[0][0];
-        }
-
-        let mut sign = 1.;
-        let mut matrix = self.clone();
-        for k in 0..(rows - 1) {
-            // Pivot row swap if needed
-            if matrix[k][k] == 0. {
-                let mut m = k + 1;
-                while m < rows {
-                    if matrix[m][k] != 0. {
-                        matrix.elements.swap(m, k);
-                        sign = -sign;
-                        break;
-                    }
-                    m += 1;
-                }
-                if m == rows {
-                    return 0.;
-                }
-            }
-
-            // Formula
-            for i in (k + 1)..rows {
-                for j in (k + 1)..cols {
-                    matrix[i][j] = matrix[k][k] * matrix[i][j] - matrix[i][k] * matrix[k][j];
-                    if k != 0 {
-                        matrix[i][j] /= matrix[k - 1][k - 1];
-                    }
-                }
-            }
-        }
+    pub fn inverse(&self) -> Result<Matrix<K>, String> {
+        self.inverse_traced(None)
+    }
 
-        sign * matrix[rows - 1][rows - 1]
+    // Inverse, with each of the row operations applied on the augmented matrix [self | identity]
+    pub fn inverse_steps(&self) -> Result<(Matrix<K>, Vec<Step<K>>), String> {
+        let mut steps = vec![];
+        let result = self.inverse_traced(Some(&mut steps))?;
+        Ok((result, steps))
     }
 
-    pub fn inverse(&self) -> Result<Matrix, String> {
+    fn inverse_traced(&self, mut steps: Option<&mut Vec<Step<K>>>) -> Result<Matrix<K>, String> {
         let [rows, cols] = self.shape();
         if rows != cols {
-            return Ok(Matrix::new([0, 0]));
+            return Ok(Matrix::zeros([0, 0]));
         }
 
         if rows < 1 {
-            return Ok(Matrix::new([0, 0]));
+            return Ok(Matrix::zeros([0, 0]));
         } else if rows < 2 {
-            return Ok(Matrix::clone(self));
+            let value = self[(0, 0)].clone();
+            if value.is_zero() {
+                return Err("Singular matrix".to_string());
+            }
+            return Ok(Matrix::from(vec![vec![K::one() / value]]));
         }
 
         let mut lead = 0;
@@ -546,13 +1357,20 @@ impl Matrix {
         // * Calculate the reduced row echelon form
         // * -- while updating the augmented matrix
         let mut reduced = Matrix::clone(self);
-        let mut result = Matrix::identity(rows, 1.);
+        let mut result = Matrix::identity(rows, K::one());
+        let augmented = |reduced: &Matrix<K>, result: &Matrix<K>| Matrix {
+            elements: reduced
+                .iter_rows()
+                .zip(result.iter_rows())
+                .map(|(left, right)| left.iter().chain(right.iter()).cloned().collect())
+                .collect(),
+        };
         for r in 0..rows {
             if cols <= lead {
                 return Err("Singular matrix".to_string());
             }
             let mut i = r;
-            while reduced[i][lead] == 0. {
+            while reduced[i][lead].is_zero() {
                 i += 1;
                 if rows == i {
                     i = r;
@@ -565,21 +1383,36 @@ impl Matrix {
 
             reduced.elements.swap(i, r);
             result.elements.swap(i, r);
+            if i != r {
+                trace::record(&mut steps, Operation::Swap(i, r), || {
+                    augmented(&reduced, &result)
+                });
+            }
 
-            let val = reduced[r][lead];
+            let val = reduced[r][lead].clone();
             for j in 0..cols {
-                reduced[r][j] /= val;
-                result[r][j] /= val;
+                reduced[r][j] = reduced[r][j].clone() / val.clone();
+                result[r][j] = result[r][j].clone() / val.clone();
+            }
+            if val != K::one() {
+                trace::record(&mut steps, Operation::Scale(r, K::one() / val), || {
+                    augmented(&reduced, &result)
+                });
             }
 
             for i in 0..rows {
                 if i == r {
                     continue;
                 }
-                let val = reduced[i][lead];
+                let val = reduced[i][lead].clone();
                 for j in 0..cols {
-                    reduced[i][j] -= val * reduced[r][j];
-                    result[i][j] -= val * result[r][j];
+                    reduced[i][j] = reduced[i][j].clone() - val.clone() * reduced[r][j].clone();
+                    result[i][j] = result[i][j].clone() - val.clone() * result[r][j].clone();
+                }
+                if !val.is_zero() {
+                    trace::record(&mut steps, Operation::AddMultiple(i, r, -val), || {
+                        augmented(&reduced, &result)
+                    });
                 }
             }
 
@@ -590,6 +1423,22 @@ impl Matrix {
     }
 
     pub fn rank(&self) -> usize {
+        self.rank_traced(None, K::is_zero)
+    }
+
+    // Rank, with each of the row operations of the gaussian elimination
+    pub fn rank_steps(&self) -> (usize, Vec<Step<K>>) {
+        let mut steps = vec![];
+        let result = self.rank_traced(Some(&mut steps), K::is_zero);
+        (result, steps)
+    }
+
+    // The columns where the best pivot is negligible are skipped
+    pub(crate) fn rank_traced(
+        &self,
+        mut steps: Option<&mut Vec<Step<K>>>,
+        is_negligible: impl Fn(&K) -> bool,
+    ) -> usize {
         let [rows, cols] = self.shape();
 
         // * Calculate the row echelon form (not reduced) with gaussian elimination
@@ -599,19 +1448,27 @@ impl Matrix {
         while h < rows && k < cols {
             let mut i_max = h;
             for i in (h + 1)..rows {
-                if reduced[i][k].abs() > reduced[i_max][k].abs() {
+                if reduced[i][k].is_better_pivot(&reduced[i_max][k]) {
                     i_max = i;
                 }
             }
-            if reduced[i_max][k] == 0. {
+            if is_negligible(&reduced[i_max][k]) {
                 k += 1;
             } else {
                 reduced.elements.swap(h, i_max);
+                if h != i_max {
+                    trace::record(&mut steps, Operation::Swap(i_max, h), || reduced.clone());
+                }
                 for i in (h + 1)..rows {
-                    let f = reduced[i][k] / reduced[h][k];
-                    reduced[i][k] = 0.;
+                    let f = reduced[i][k].clone() / reduced[h][k].clone();
+                    reduced[i][k] = K::zero();
                     for j in (k + 1)..cols {
-                        reduced[i][j] -= reduced[h][j] * f;
+                        reduced[i][j] = reduced[i][j].clone() - reduced[h][j].clone() * f.clone();
+                    }
+                    if !f.is_zero() {
+                        trace::record(&mut steps, Operation::AddMultiple(i, h, -f), || {
+                            reduced.clone()
+                        });
                     }
                 }
                 h += 1;
@@ -624,15 +1481,85 @@ impl Matrix {
         h
     }
 
-    pub fn projection(fov: f64, ratio: f64, near: f64, far: f64) -> Matrix {
-        let s = 1. / (f64::tan((fov / 2.) * (PI / 180.)));
-        let x_scale = if ratio < 1. { ratio } else { 1. };
-        let y_scale = if ratio > 1. { 1. / ratio } else { 1. };
-        Matrix::from([
-            [s * x_scale, 0., 0., 0.],
-            [0., s * y_scale, 0., 0.],
-            [0., 0., -far / (far - near), -1.],
-            [0., 0., -((far * near) / (far - near)), 0.],
-        ])
+    // Basis of the vectors x such that `self * x = 0`, found from the reduced row echelon form
+    // -- floating point values are compared exactly, which is only reliable for exact scalars
+    pub fn null_space(&self) -> Vec<Vector<K>> {
+        let [rows, cols] = self.shape();
+        let reduced = self.row_echelon();
+
+        // * Column of the pivot of each non-zero row
+        let pivots: Vec<usize> = (0..rows)
+            .filter_map(|row| (0..cols).find(|column| !reduced[row][*column].is_zero()))
+            .collect();
+
+        // * One vector for each of the free columns
+        (0..cols)
+            .filter(|column| !pivots.contains(column))
+            .map(|free| {
+                let mut vector = Vector::zeros(cols);
+                vector[free] = K::one();
+                for (row, pivot) in pivots.iter().enumerate() {
+                    vector[*pivot] = -reduced[row][free].clone();
+                }
+                vector
+            })
+            .collect()
+    }
+
+    // Solve the system `self * x = b` with gaussian elimination and partial pivoting
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        let [rows, cols] = self.shape();
+        if rows != cols {
+            return Err(format!(
+                "Expected a square matrix, got shape {:?}",
+                [rows, cols]
+            ));
+        }
+        if b.size() != rows {
+            return Err(format!(
+                "Invalid vector length, got {} expected {}",
+                b.size(),
+                rows
+            ));
+        }
+
+        // * Reduce the augmented matrix to an upper triangular form
+        let mut reduced = self.clone();
+        let mut result = b.clone();
+        for k in 0..rows {
+            let mut i_max = k;
+            for i in (k + 1)..rows {
+                if reduced[i][k].is_better_pivot(&reduced[i_max][k]) {
+                    i_max = i;
+                }
+            }
+            if reduced[i_max][k].is_zero() {
+                return Err("Singular matrix".to_string());
+            }
+            reduced.elements.swap(k, i_max);
+            let value = result[k].clone();
+            result[k] = result[i_max].clone();
+            result[i_max] = value;
+
+            for i in (k + 1)..rows {
+                let f = reduced[i][k].clone() / reduced[k][k].clone();
+                reduced[i][k] = K::zero();
+                for j in (k + 1)..cols {
+                    reduced[i][j] = reduced[i][j].clone() - reduced[k][j].clone() * f.clone();
+                }
+                result[i] = result[i].clone() - result[k].clone() * f;
+            }
+        }
+
+        // * Back substitution
+        for k in (0..rows).rev() {
+            let mut value = result[k].clone();
+            for j in (k + 1)..cols {
+                value = value - reduced[k][j].clone() * result[j].clone();
+            }
+            result[k] = value / reduced[k][k].clone();
+        }
+
+        Ok(result)
     }
 }
@@ -0,0 +1,230 @@
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    export::{ExportOptions, Latex},
+    matrix::Matrix,
+    scalar::{Field, Ring, Scalar, Semiring},
+};
+
+const fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+// Deterministic Miller-Rabin test, those bases are enough for every 64-bit value
+// @see https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test#Testing_against_small_sets_of_bases
+const fn is_prime(value: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if value < 2 {
+        return false;
+    }
+    let mut index = 0;
+    while index < BASES.len() {
+        if value == BASES[index] {
+            return true;
+        }
+        if value.is_multiple_of(BASES[index]) {
+            return false;
+        }
+        index += 1;
+    }
+
+    // value - 1 = d * 2^s with d odd
+    let s = (value - 1).trailing_zeros();
+    let d = (value - 1) >> s;
+    let mut index = 0;
+    while index < BASES.len() {
+        let mut x = pow_mod(BASES[index], d, value);
+        if x != 1 && x != value - 1 {
+            let mut round = 1;
+            while round < s && x != value - 1 {
+                x = mul_mod(x, x, value);
+                round += 1;
+            }
+            if x != value - 1 {
+                return false;
+            }
+        }
+        index += 1;
+    }
+    true
+}
+
+// Integer modulo the prime P, always stored in [0, P)
+// Using a P that isn't a prime fails to compile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Zp<const P: u64>(u64);
+
+impl<const P: u64> Zp<P> {
+    const PRIME: () = assert!(is_prime(P), "The modulus of Zp must be a prime");
+
+    pub fn new(value: i128) -> Zp<P> {
+        let () = Self::PRIME;
+        Zp(value.rem_euclid(P as i128) as u64)
+    }
+
+    // Representative in [0, P)
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(&self, mut exponent: u64) -> Zp<P> {
+        let mut base = *self;
+        let mut result = Zp::new(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    // Multiplicative inverse, None for zero
+    pub fn inverse(&self) -> Option<Zp<P>> {
+        if self.0 == 0 {
+            None
+        } else {
+            // Fermat's little theorem: a^(P - 1) = 1
+            Some(self.pow(P - 2))
+        }
+    }
+}
+
+// * Operations
+
+impl<const P: u64> Add for Zp<P> {
+    type Output = Zp<P>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Zp(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Zp<P> {
+    type Output = Zp<P>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Mul for Zp<P> {
+    type Output = Zp<P>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Zp(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+// Multiply by the inverse
+impl<const P: u64> Div for Zp<P> {
+    type Output = Zp<P>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("Zp division by zero")
+    }
+}
+
+impl<const P: u64> Neg for Zp<P> {
+    type Output = Zp<P>;
+
+    fn neg(self) -> Self::Output {
+        if self.0 == 0 {
+            self
+        } else {
+            Zp(P - self.0)
+        }
+    }
+}
+
+impl<const P: u64> fmt::Display for Zp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// *> From
+
+impl<const P: u64> From<i64> for Zp<P> {
+    fn from(value: i64) -> Self {
+        Zp::new(value.into())
+    }
+}
+
+impl<const P: u64> From<i32> for Zp<P> {
+    fn from(value: i32) -> Self {
+        Zp::new(value.into())
+    }
+}
+
+// *< From
+
+// * Scalar
+
+impl<const P: u64> Scalar for Zp<P> {
+    fn zero() -> Self {
+        Zp::new(0)
+    }
+
+    fn one() -> Self {
+        Zp::new(1)
+    }
+}
+
+impl<const P: u64> Semiring for Zp<P> {}
+
+impl<const P: u64> Ring for Zp<P> {}
+
+impl<const P: u64> Field for Zp<P> {}
+
+// * Export
+
+impl<const P: u64> Latex for Zp<P> {
+    fn to_latex(&self, _options: &ExportOptions) -> String {
+        self.to_string()
+    }
+}
+
+// * Conversions
+
+impl Matrix {
+    // Reduce each of the elements modulo P, fails if any of them isn't an integer
+    pub fn to_modular<const P: u64>(&self) -> Result<Matrix<Zp<P>>, String> {
+        let rows = self
+            .iter_rows()
+            .map(|row| {
+                row.iter()
+                    .map(|value| {
+                        if value.is_finite() && value.fract() == 0. {
+                            Ok(Zp::new(*value as i128))
+                        } else {
+                            Err(format!(
+                                "Can't convert {} to an integer modulo {}",
+                                value, P
+                            ))
+                        }
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<Zp<P>>>, String>>()?;
+        Ok(Matrix::from(rows))
+    }
+}
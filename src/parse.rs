@@ -0,0 +1,304 @@
+use std::{error::Error, fmt};
+
+// Error returned when a matrix or a vector can't be parsed from text
+// The line and column are 1-based and point to the offending character
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(position: Position, message: String) -> ParseError {
+        ParseError {
+            line: position.line,
+            column: position.column,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.message, self.line, self.column
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Comma,
+    Semicolon,
+    Newline,
+    Number(f64),
+    End,
+}
+
+fn describe(token: Token) -> String {
+    match token {
+        Token::Open => "'['".to_string(),
+        Token::Close => "']'".to_string(),
+        Token::Comma => "','".to_string(),
+        Token::Semicolon => "';'".to_string(),
+        Token::Newline => "end of line".to_string(),
+        Token::Number(value) => format!("number {}", value),
+        Token::End => "end of input".to_string(),
+    }
+}
+
+fn is_separator(character: char) -> bool {
+    character.is_whitespace() || matches!(character, '[' | ']' | ',' | ';')
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, Position)>, ParseError> {
+    let mut tokens = vec![];
+    let mut position = Position { line: 1, column: 1 };
+    let mut characters = input.chars().peekable();
+    while let Some(character) = characters.next() {
+        let start = position;
+        position.column += 1;
+        let token = match character {
+            '\n' => {
+                position.line += 1;
+                position.column = 1;
+                Token::Newline
+            }
+            '[' => Token::Open,
+            ']' => Token::Close,
+            ',' => Token::Comma,
+            ';' => Token::Semicolon,
+            _ if character.is_whitespace() => continue,
+            _ => {
+                let mut word = character.to_string();
+                while let Some(next) = characters.peek() {
+                    if is_separator(*next) {
+                        break;
+                    }
+                    word.push(*next);
+                    characters.next();
+                    position.column += 1;
+                }
+                match word.parse::<f64>() {
+                    Ok(value) => Token::Number(value),
+                    Err(_) => {
+                        return Err(ParseError::new(start, format!("Invalid number '{}'", word)))
+                    }
+                }
+            }
+        };
+        tokens.push((token, start));
+    }
+    tokens.push((Token::End, position));
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, Position)>,
+    current: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Token {
+        self.tokens[self.current].0
+    }
+
+    fn position(&self) -> Position {
+        self.tokens[self.current].1
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.peek();
+        if token != Token::End {
+            self.current += 1;
+        }
+        token
+    }
+
+    fn skip_newlines(&mut self) {
+        while self.peek() == Token::Newline {
+            self.advance();
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> ParseError {
+        ParseError::new(
+            self.position(),
+            format!("Expected {}, found {}", expected, describe(self.peek())),
+        )
+    }
+
+    fn expect(&mut self, token: Token) -> Result<(), ParseError> {
+        if self.peek() != token {
+            return Err(self.unexpected(&describe(token)));
+        }
+        self.advance();
+        Ok(())
+    }
+
+    // Parse values separated by commas or whitespace, stopping on any other token
+    fn row(&mut self, allow_newlines: bool) -> Result<Vec<f64>, ParseError> {
+        let mut row = vec![];
+        loop {
+            if allow_newlines {
+                self.skip_newlines();
+            }
+            match self.peek() {
+                Token::Number(value) => {
+                    self.advance();
+                    row.push(value);
+                }
+                _ => return Ok(row),
+            }
+            if self.peek() == Token::Comma {
+                self.advance();
+                if allow_newlines {
+                    self.skip_newlines();
+                }
+                if !matches!(self.peek(), Token::Number(_)) {
+                    return Err(self.unexpected("a number"));
+                }
+            }
+        }
+    }
+
+    // `[[1, 2], [3, 4]]`, where the first bracket has already been consumed
+    fn nested(&mut self) -> Result<Vec<(Vec<f64>, Position)>, ParseError> {
+        let mut rows = vec![];
+        loop {
+            self.skip_newlines();
+            let position = self.position();
+            self.expect(Token::Open)?;
+            let row = self.row(true)?;
+            self.skip_newlines();
+            self.expect(Token::Close)?;
+            rows.push((row, position));
+            self.skip_newlines();
+            match self.peek() {
+                Token::Comma => {
+                    self.advance();
+                    // Allow a trailing comma after the last row
+                    self.skip_newlines();
+                    if self.peek() == Token::Close {
+                        self.advance();
+                        return Ok(rows);
+                    }
+                }
+                Token::Close => {
+                    self.advance();
+                    return Ok(rows);
+                }
+                Token::Open => {}
+                _ => return Err(self.unexpected("',' or ']'")),
+            }
+        }
+    }
+
+    // `1 2; 3 4` or lines of values, optionally surrounded by brackets
+    fn flat(&mut self, bracketed: bool) -> Result<Vec<(Vec<f64>, Position)>, ParseError> {
+        let mut rows = vec![];
+        loop {
+            let position = self.position();
+            let row = self.row(false)?;
+            if !row.is_empty() {
+                rows.push((row, position));
+            }
+            match self.peek() {
+                Token::Semicolon | Token::Newline => {
+                    self.advance();
+                }
+                Token::Close if bracketed => {
+                    self.advance();
+                    return Ok(rows);
+                }
+                Token::End if !bracketed => return Ok(rows),
+                _ if bracketed => return Err(self.unexpected("a number, ';' or ']'")),
+                _ => return Err(self.unexpected("a number, ';' or end of line")),
+            }
+        }
+    }
+
+    // Rows with the position of their first token
+    fn parse(&mut self) -> Result<Vec<(Vec<f64>, Position)>, ParseError> {
+        self.skip_newlines();
+        let rows = if self.peek() == Token::Open {
+            self.advance();
+            self.skip_newlines();
+            if self.peek() == Token::Open {
+                self.nested()?
+            } else {
+                self.flat(true)?
+            }
+        } else {
+            self.flat(false)?
+        };
+        self.skip_newlines();
+        if self.peek() != Token::End {
+            return Err(self.unexpected("end of input"));
+        }
+
+        // * Validate that all rows have the same length
+        if let Some((first, _)) = rows.first() {
+            let columns = first.len();
+            for (index, (row, position)) in rows.iter().enumerate() {
+                if row.len() != columns {
+                    return Err(ParseError::new(
+                        *position,
+                        format!(
+                            "Row {} has {} columns, expected {}",
+                            index + 1,
+                            row.len(),
+                            columns
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(rows)
+    }
+}
+
+// Parse the rows of a matrix from text
+// Accepts `[1 2; 3 4]`, `[[1, 2], [3, 4]]` and lines of values separated by whitespace or commas
+pub(crate) fn parse_rows(input: &str) -> Result<Vec<Vec<f64>>, ParseError> {
+    let rows = parse_positioned(input)?;
+    Ok(rows.into_iter().map(|(row, _)| row).collect())
+}
+
+fn parse_positioned(input: &str) -> Result<Vec<(Vec<f64>, Position)>, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        current: 0,
+    };
+    parser.parse()
+}
+
+// Parse the elements of a vector, written as a single row or a single column
+pub(crate) fn parse_elements(input: &str) -> Result<Vec<f64>, ParseError> {
+    let rows = parse_positioned(input)?;
+    if rows.len() <= 1 || rows.iter().all(|(row, _)| row.len() == 1) {
+        return Ok(rows.into_iter().flat_map(|(row, _)| row).collect());
+    }
+    // The rows all have the same length, the second one is the first that doesn't fit in a vector
+    Err(ParseError::new(
+        rows[1].1,
+        format!(
+            "Expected a single row or column, got shape {:?}",
+            [rows.len(), rows[0].0.len()]
+        ),
+    ))
+}
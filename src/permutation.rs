@@ -0,0 +1,121 @@
+use std::fmt;
+
+use crate::{matrix::Matrix, scalar::Scalar};
+
+// Permutation of the indices 0..n, the value at index i is the index that moves to position i
+// -- applied to the rows of a matrix, row i of the result is row `indices[i]` of the original
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl fmt::Display for Permutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.indices)
+    }
+}
+
+impl Default for Permutation {
+    fn default() -> Self {
+        Self::identity(0)
+    }
+}
+
+// *> From
+
+impl TryFrom<Vec<usize>> for Permutation {
+    type Error = String;
+
+    fn try_from(indices: Vec<usize>) -> Result<Self, Self::Error> {
+        Permutation::new(indices)
+    }
+}
+
+// *< From
+
+impl Permutation {
+    // Fails if the indices aren't each of 0..n exactly once
+    pub fn new(indices: Vec<usize>) -> Result<Permutation, String> {
+        let mut seen = vec![false; indices.len()];
+        for index in indices.iter() {
+            if *index >= indices.len() || seen[*index] {
+                return Err(format!("{:?} isn't a permutation", indices));
+            }
+            seen[*index] = true;
+        }
+        Ok(Permutation { indices })
+    }
+
+    pub fn identity(size: usize) -> Permutation {
+        Permutation {
+            indices: (0..size).collect(),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    // Swap the values at the positions a and b
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.indices.swap(a, b);
+    }
+
+    // Permutation that undoes this one
+    pub fn inverse(&self) -> Permutation {
+        let mut indices = vec![0; self.size()];
+        for (position, index) in self.indices.iter().enumerate() {
+            indices[*index] = position;
+        }
+        Permutation { indices }
+    }
+
+    // Apply `other` first then this permutation, as the product of their matrices self * other
+    pub fn compose(&self, other: &Permutation) -> Result<Permutation, String> {
+        if self.size() != other.size() {
+            return Err(format!(
+                "Invalid sizes {} and {}",
+                self.size(),
+                other.size()
+            ));
+        }
+        Ok(Permutation {
+            indices: self.indices.iter().map(|i| other.indices[*i]).collect(),
+        })
+    }
+
+    // 1 for an even number of transpositions, -1 for an odd number, the determinant of its matrix
+    pub fn sign(&self) -> i32 {
+        let mut visited = vec![false; self.size()];
+        let mut transpositions: usize = 0;
+        for start in 0..self.size() {
+            let mut index = start;
+            let mut length = 0;
+            while !visited[index] {
+                visited[index] = true;
+                index = self.indices[index];
+                length += 1;
+            }
+            // A cycle of length n is made of n - 1 transpositions
+            transpositions += length.max(1) - 1;
+        }
+        if transpositions.is_multiple_of(2) {
+            1
+        } else {
+            -1
+        }
+    }
+
+    // Permutation matrix P such that P * A permutes the rows of A
+    pub fn to_matrix<K: Scalar>(&self) -> Matrix<K> {
+        let mut matrix = Matrix::zeros([self.size(), self.size()]);
+        for (row, column) in self.indices.iter().enumerate() {
+            matrix[row][*column] = K::one();
+        }
+        matrix
+    }
+}
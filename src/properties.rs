@@ -0,0 +1,126 @@
+use crate::{
+    matrix::Matrix,
+    scalar::{Field, Scalar},
+};
+
+// * Exact structure
+
+impl<K: Scalar> Matrix<K> {
+    pub fn is_square(&self) -> bool {
+        let [rows, cols] = self.shape();
+        rows == cols
+    }
+
+    // Check that every element for which the predicate is false is zero
+    fn zero_outside(&self, keep: impl Fn(usize, usize) -> bool) -> bool {
+        self.indexed_iter()
+            .all(|((row, column), value)| keep(row, column) || value.is_zero())
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.is_square() && self.zero_outside(|row, column| row == column)
+    }
+
+    // Every element below the diagonal is zero, the matrix doesn't need to be square
+    pub fn is_upper_triangular(&self) -> bool {
+        self.zero_outside(|row, column| row <= column)
+    }
+
+    // Every element above the diagonal is zero, the matrix doesn't need to be square
+    pub fn is_lower_triangular(&self) -> bool {
+        self.zero_outside(|row, column| row >= column)
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.is_diagonal()
+            && (0..self.shape()[0]).all(|diagonal| self[(diagonal, diagonal)] == K::one())
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    // Rank where the pivots that are negligible compared to the largest element count as zero
+    fn relative_rank(&self, is_negligible: impl Fn(&K, &K) -> bool) -> usize {
+        let largest = self.iter().fold(K::zero(), |largest, value| {
+            if value.is_better_pivot(&largest) {
+                value.clone()
+            } else {
+                largest
+            }
+        });
+        self.rank_traced(None, |pivot| is_negligible(pivot, &largest))
+    }
+
+    // Exact for exact scalars, floating points are the same as `!is_singular(Matrix::SINGULAR_TOLERANCE)`
+    pub fn is_invertible(&self) -> bool {
+        self.is_square() && self.relative_rank(K::is_negligible) == self.shape()[0]
+    }
+}
+
+// * Floating point structure, with a tolerance on each element or relative to the largest one
+
+impl Matrix {
+    // Default tolerance of `is_invertible`, relative to the largest element of the matrix
+    pub const SINGULAR_TOLERANCE: f64 = 1e-12;
+
+    // A = A^T
+    pub fn is_symmetric(&self, tolerance: f64) -> bool {
+        self.is_square()
+            && self
+                .indexed_iter()
+                .all(|((row, column), value)| (value - self[(column, row)]).abs() <= tolerance)
+    }
+
+    // A = -A^T, which implies a zero diagonal
+    pub fn is_skew_symmetric(&self, tolerance: f64) -> bool {
+        self.is_square()
+            && self
+                .indexed_iter()
+                .all(|((row, column), value)| (value + self[(column, row)]).abs() <= tolerance)
+    }
+
+    // A^T A = I, the columns are orthonormal
+    pub fn is_orthogonal(&self, tolerance: f64) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        let product = self.transpose().mul_mat(self);
+        product.indexed_iter().all(|((row, column), value)| {
+            let expected = if row == column { 1. } else { 0. };
+            (value - expected).abs() <= tolerance
+        })
+    }
+
+    // A pivot of the gaussian elimination is at most the tolerance times the largest element,
+    // unlike the determinant this doesn't depend on the scale of the matrix
+    // -- non-square matrices are singular
+    pub fn is_singular(&self, tolerance: f64) -> bool {
+        let is_negligible = |pivot: &f64, largest: &f64| pivot.abs() <= tolerance * largest.abs();
+        !self.is_square() || self.relative_rank(is_negligible) < self.shape()[0]
+    }
+
+    // Symmetric with only positive eigenvalues, checked with a Cholesky decomposition A = L L^T
+    pub fn is_positive_definite(&self) -> bool {
+        let scale = self
+            .iter()
+            .fold(0., |scale: f64, value| scale.max(value.abs()));
+        if self.shape()[0] == 0 || !self.is_symmetric(1e-10 * scale) {
+            return false;
+        }
+
+        let size = self.shape()[0];
+        let mut lower = Matrix::new([size, size]);
+        for j in 0..size {
+            let diagonal =
+                self[(j, j)] - (0..j).map(|k| lower[(j, k)] * lower[(j, k)]).sum::<f64>();
+            if diagonal <= 0. || !diagonal.is_finite() {
+                return false;
+            }
+            lower[(j, j)] = diagonal.sqrt();
+            for i in (j + 1)..size {
+                let sum: f64 = (0..j).map(|k| lower[(i, k)] * lower[(j, k)]).sum();
+                lower[(i, j)] = (self[(i, j)] - sum) / lower[(j, j)];
+            }
+        }
+        true
+    }
+}
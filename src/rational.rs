@@ -0,0 +1,396 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    export::{ExportOptions, Latex},
+    matrix::Matrix,
+    scalar::{Field, Ring, Scalar, Semiring},
+    vector::Vector,
+};
+
+// Exact fraction, always stored with a positive denominator and without common factors
+// Operators panic when a result doesn't fit in an i128, the `checked_*` functions return None instead
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let [mut a, mut b] = [a.unsigned_abs(), b.unsigned_abs()];
+    while b != 0 {
+        [a, b] = [b, a % b];
+    }
+    // Only gcd(i128::MIN, 0) and gcd(i128::MIN, i128::MIN) don't fit, they are never reduced
+    i128::try_from(a).unwrap_or(1)
+}
+
+impl Rational {
+    // Panics if the denominator is zero or if the normalised fraction doesn't fit
+    pub fn new(numerator: i128, denominator: i128) -> Rational {
+        if denominator == 0 {
+            panic!("Rational with a zero denominator");
+        }
+        Rational::checked_new(numerator, denominator).expect("Rational overflow")
+    }
+
+    // Normalised fraction, None if the denominator is zero or if it doesn't fit
+    pub fn checked_new(numerator: i128, denominator: i128) -> Option<Rational> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator);
+        let [mut numerator, mut denominator] = [numerator / divisor, denominator / divisor];
+        if denominator < 0 {
+            numerator = numerator.checked_neg()?;
+            denominator = denominator.checked_neg()?;
+        }
+        Some(Rational {
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn from_integer(value: i128) -> Rational {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    // Closest floating point value
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    pub fn abs(&self) -> Rational {
+        if self.numerator < 0 {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    // Panics if the value is zero
+    pub fn recip(&self) -> Rational {
+        Rational::new(self.denominator, self.numerator)
+    }
+
+    // * Checked operations
+
+    pub fn checked_add(&self, other: &Rational) -> Option<Rational> {
+        let divisor = gcd(self.denominator, other.denominator);
+        let left = self.numerator.checked_mul(other.denominator / divisor)?;
+        let right = other.numerator.checked_mul(self.denominator / divisor)?;
+        Rational::checked_new(
+            left.checked_add(right)?,
+            (self.denominator / divisor).checked_mul(other.denominator)?,
+        )
+    }
+
+    pub fn checked_sub(&self, other: &Rational) -> Option<Rational> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    pub fn checked_mul(&self, other: &Rational) -> Option<Rational> {
+        // Reduce the cross factors first to keep the products small
+        let first = gcd(self.numerator, other.denominator);
+        let second = gcd(other.numerator, self.denominator);
+        Rational::checked_new(
+            (self.numerator / first).checked_mul(other.numerator / second)?,
+            (self.denominator / second).checked_mul(other.denominator / first)?,
+        )
+    }
+
+    pub fn checked_div(&self, other: &Rational) -> Option<Rational> {
+        self.checked_mul(&Rational::checked_new(other.denominator, other.numerator)?)
+    }
+
+    pub fn checked_neg(&self) -> Option<Rational> {
+        Some(Rational {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+}
+
+// * Operations
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("Rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("Rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(&rhs).expect("Rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.numerator == 0 {
+            panic!("Rational division by zero");
+        }
+        self.checked_div(&rhs).expect("Rational overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("Rational overflow")
+    }
+}
+
+// Compare the continued fractions of both values, which never overflows
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let [mut a, mut b] = [self.numerator, self.denominator];
+        let [mut c, mut d] = [other.numerator, other.denominator];
+        let mut reversed = false;
+        loop {
+            let [left, right] = [a.div_euclid(b), c.div_euclid(d)];
+            let [left_rest, right_rest] = [a.rem_euclid(b), c.rem_euclid(d)];
+            let ordering = match (left.cmp(&right), left_rest, right_rest) {
+                (Ordering::Equal, 0, 0) => Ordering::Equal,
+                (Ordering::Equal, 0, _) => Ordering::Less,
+                (Ordering::Equal, _, 0) => Ordering::Greater,
+                (Ordering::Equal, _, _) => {
+                    // Compare the inverse of the remainders, which reverses the order
+                    [a, b, c, d] = [b, left_rest, d, right_rest];
+                    reversed = !reversed;
+                    continue;
+                }
+                (ordering, _, _) => ordering,
+            };
+            return if reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Integers are displayed without a denominator, e.g. `-2` or `3/2`
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+// *> From
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Rational::from_integer(value)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::from_integer(value.into())
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Self {
+        Rational::from_integer(value.into())
+    }
+}
+
+// Exact value of the floating point, e.g. 0.1 is 3602879701896397/36028797018963968
+impl TryFrom<f64> for Rational {
+    type Error = String;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(format!("Can't convert {} to a rational", value));
+        }
+        if value == 0. {
+            return Ok(Rational::from_integer(0));
+        }
+
+        // value = mantissa * 2^exponent
+        let bits = value.to_bits();
+        let sign = if bits >> 63 == 0 { 1 } else { -1 };
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as i128;
+        let (mantissa, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased - 1075)
+        };
+        let shift = mantissa.trailing_zeros() as i32;
+        let [mantissa, exponent] = [mantissa >> shift, (exponent + shift).into()];
+
+        let overflow = || format!("{} doesn't fit in a rational", value);
+        if exponent >= 0 {
+            let factor = 1_i128.checked_shl(exponent as u32).filter(|f| *f > 0);
+            let numerator = factor
+                .and_then(|factor| mantissa.checked_mul(factor))
+                .ok_or_else(overflow)?;
+            Ok(Rational::from_integer(sign * numerator))
+        } else {
+            let denominator = 1_i128
+                .checked_shl(-exponent as u32)
+                .filter(|d| *d > 0)
+                .ok_or_else(overflow)?;
+            Ok(Rational::new(sign * mantissa, denominator))
+        }
+    }
+}
+
+// Parse an integer, a fraction or a decimal value, e.g. `-2`, `3/2` or `0.75`
+impl FromStr for Rational {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || format!("Invalid rational '{}'", s);
+        let integer = |part: &str| part.trim().parse::<i128>().map_err(|_| invalid());
+
+        if let Some((numerator, denominator)) = s.split_once('/') {
+            return Rational::checked_new(integer(numerator)?, integer(denominator)?)
+                .ok_or_else(invalid);
+        }
+        if let Some((whole, decimals)) = s.split_once('.') {
+            if decimals.is_empty() || !decimals.chars().all(|c| c.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            let scale = 10_i128
+                .checked_pow(decimals.len() as u32)
+                .ok_or_else(invalid)?;
+            let numerator = integer(&format!("{}{}", whole, decimals))?;
+            return Ok(Rational::new(numerator, scale));
+        }
+        Ok(Rational::from_integer(integer(s)?))
+    }
+}
+
+// *< From
+
+// * Scalar
+
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Rational::from_integer(0)
+    }
+
+    fn one() -> Self {
+        Rational::from_integer(1)
+    }
+
+    fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+}
+
+impl Semiring for Rational {}
+
+impl Ring for Rational {}
+
+impl Field for Rational {}
+
+// * Export
+
+// Exact, the options only apply to floating points
+impl Latex for Rational {
+    fn to_latex(&self, _options: &ExportOptions) -> String {
+        if self.denominator == 1 {
+            return self.numerator.to_string();
+        }
+        let sign = if self.numerator < 0 { "-" } else { "" };
+        format!(
+            "{}\\frac{{{}}}{{{}}}",
+            sign,
+            self.numerator.unsigned_abs(),
+            self.denominator
+        )
+    }
+}
+
+// * Conversions
+
+impl Matrix {
+    // Exact rational value of each of the elements
+    pub fn to_rational(&self) -> Result<Matrix<Rational>, String> {
+        let rows = self
+            .iter_rows()
+            .map(|row| row.iter().map(|value| Rational::try_from(*value)).collect())
+            .collect::<Result<Vec<Vec<Rational>>, String>>()?;
+        Ok(Matrix::from(rows))
+    }
+}
+
+impl Matrix<Rational> {
+    // Closest floating point value of each of the elements
+    pub fn to_f64(&self) -> Matrix {
+        Matrix::from(
+            self.iter_rows()
+                .map(|row| row.iter().map(Rational::to_f64).collect())
+                .collect::<Vec<Vec<f64>>>(),
+        )
+    }
+}
+
+impl Vector {
+    // Exact rational value of each of the elements
+    pub fn to_rational(&self) -> Result<Vector<Rational>, String> {
+        let elements = self
+            .iter()
+            .map(|value| Rational::try_from(*value))
+            .collect::<Result<Vec<Rational>, String>>()?;
+        Ok(Vector::from(elements))
+    }
+}
+
+impl Vector<Rational> {
+    // Closest floating point value of each of the elements
+    pub fn to_f64(&self) -> Vector {
+        Vector::from(self.iter().map(Rational::to_f64).collect::<Vec<f64>>())
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{matrix::Matrix, vector::Vector};
+
+// Direction of a reduction on a matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    // One value for each row, computed from the elements of the row
+    Rows,
+    // One value for each column, computed from the elements of the column
+    Columns,
+}
+
+// * Reductions of a list of values
+
+fn sum(values: &[f64]) -> f64 {
+    values.iter().sum()
+}
+
+// NaN if there are no values
+fn mean(values: &[f64]) -> f64 {
+    sum(values) / values.len() as f64
+}
+
+// Sample variance, with n - 1 degrees of freedom, NaN if there are less than 2 values
+fn variance(values: &[f64]) -> f64 {
+    let mean = mean(values);
+    values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / (values.len() as f64 - 1.)
+}
+
+// Index of the first value that is better than all the others, NaN values are ignored
+fn position(values: &[f64], better: fn(f64, f64) -> bool) -> Option<usize> {
+    values
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| !value.is_nan())
+        .fold(None, |best: Option<usize>, (index, value)| match best {
+            Some(best) if !better(*value, values[best]) => Some(best),
+            _ => Some(index),
+        })
+}
+
+fn argmin(values: &[f64]) -> Option<usize> {
+    position(values, |a, b| a < b)
+}
+
+fn argmax(values: &[f64]) -> Option<usize> {
+    position(values, |a, b| a > b)
+}
+
+// * Matrix
+
+impl Matrix {
+    fn values(&self) -> Vec<f64> {
+        self.iter().copied().collect()
+    }
+
+    fn lanes(&self, axis: Axis) -> Vec<Vec<f64>> {
+        match axis {
+            Axis::Rows => self.all().to_vec(),
+            Axis::Columns => self.transpose().all().to_vec(),
+        }
+    }
+
+    fn reduce_axis(&self, axis: Axis, reduction: fn(&[f64]) -> f64) -> Vector {
+        Vector::from(
+            self.lanes(axis)
+                .iter()
+                .map(|lane| reduction(lane))
+                .collect::<Vec<f64>>(),
+        )
+    }
+
+    // Index of the element as [row, column]
+    fn flat_position(&self, index: usize) -> [usize; 2] {
+        let ((row, column), _) = self.indexed_iter().nth(index).unwrap();
+        [row, column]
+    }
+
+    pub fn sum(&self) -> f64 {
+        sum(&self.values())
+    }
+
+    pub fn sum_axis(&self, axis: Axis) -> Vector {
+        self.reduce_axis(axis, sum)
+    }
+
+    pub fn mean(&self) -> f64 {
+        mean(&self.values())
+    }
+
+    pub fn mean_axis(&self, axis: Axis) -> Vector {
+        self.reduce_axis(axis, mean)
+    }
+
+    // Sample variance, see `Vector::variance`
+    pub fn variance(&self) -> f64 {
+        variance(&self.values())
+    }
+
+    pub fn variance_axis(&self, axis: Axis) -> Vector {
+        self.reduce_axis(axis, variance)
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    pub fn std_dev_axis(&self, axis: Axis) -> Vector {
+        self.reduce_axis(axis, |values| variance(values).sqrt())
+    }
+
+    // Position of the smallest element as [row, column], None if the matrix is empty
+    pub fn argmin(&self) -> Option<[usize; 2]> {
+        argmin(&self.values()).map(|index| self.flat_position(index))
+    }
+
+    // Position of the smallest element of each row or column
+    pub fn argmin_axis(&self, axis: Axis) -> Vec<Option<usize>> {
+        self.lanes(axis).iter().map(|lane| argmin(lane)).collect()
+    }
+
+    pub fn argmax(&self) -> Option<[usize; 2]> {
+        argmax(&self.values()).map(|index| self.flat_position(index))
+    }
+
+    pub fn argmax_axis(&self, axis: Axis) -> Vec<Option<usize>> {
+        self.lanes(axis).iter().map(|lane| argmax(lane)).collect()
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.argmin().map(|[row, column]| self[row][column])
+    }
+
+    // NaN for a row or column without values
+    pub fn min_axis(&self, axis: Axis) -> Vector {
+        self.reduce_axis(axis, |values| {
+            argmin(values).map_or(f64::NAN, |index| values[index])
+        })
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        self.argmax().map(|[row, column]| self[row][column])
+    }
+
+    pub fn max_axis(&self, axis: Axis) -> Vector {
+        self.reduce_axis(axis, |values| {
+            argmax(values).map_or(f64::NAN, |index| values[index])
+        })
+    }
+}
+
+// * Vector
+
+impl Vector {
+    pub fn sum(&self) -> f64 {
+        sum(self.all())
+    }
+
+    // NaN for an empty vector
+    pub fn mean(&self) -> f64 {
+        mean(self.all())
+    }
+
+    // Sample variance, the sum of the squared deviations divided by n - 1
+    pub fn variance(&self) -> f64 {
+        variance(self.all())
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    // Index of the first smallest value, NaN values are ignored
+    pub fn argmin(&self) -> Option<usize> {
+        argmin(self.all())
+    }
+
+    pub fn argmax(&self) -> Option<usize> {
+        argmax(self.all())
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.argmin().map(|index| self[index])
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        self.argmax().map(|index| self[index])
+    }
+}
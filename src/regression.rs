@@ -0,0 +1,203 @@
+use crate::{matrix::Matrix, reduction::Axis, vector::Vector};
+
+// Result of a least squares fit y ≈ X b
+#[derive(Debug, Clone)]
+pub struct LinearFit {
+    // Intercept first, then one coefficient for each feature
+    pub coefficients: Vector,
+    // y - X b for each observation
+    pub residuals: Vector,
+    // Coefficient of determination, 1 - RSS / TSS, NaN if y is constant since TSS is zero
+    pub r_squared: f64,
+    // Standard error of each coefficient, NaN if there are no degrees of freedom left
+    pub standard_errors: Vector,
+}
+
+// Upper triangular factor R and Q^T y of the Householder QR decomposition of the design matrix
+fn householder(design: &Matrix, y: &Vector) -> (Matrix, Vec<f64>) {
+    let [rows, cols] = design.shape();
+    let mut a = design.clone();
+    let mut b = y.all().to_vec();
+    for k in 0..cols {
+        let norm = (k..rows).map(|i| a[i][k] * a[i][k]).sum::<f64>().sqrt();
+        if norm == 0. {
+            continue;
+        }
+        // Reflect the column on -sign(a[k][k]) ||x|| e_k to avoid a cancellation
+        let alpha = if a[k][k] > 0. { -norm } else { norm };
+        let mut v: Vec<f64> = (k..rows).map(|i| a[i][k]).collect();
+        v[0] -= alpha;
+        let v_norm = v.iter().map(|value| value * value).sum::<f64>();
+
+        for column in k..cols {
+            let dot: f64 = (k..rows).map(|i| v[i - k] * a[i][column]).sum();
+            let factor = 2. * dot / v_norm;
+            for i in k..rows {
+                a[i][column] -= factor * v[i - k];
+            }
+        }
+        let dot: f64 = (k..rows).map(|i| v[i - k] * b[i]).sum();
+        let factor = 2. * dot / v_norm;
+        for i in k..rows {
+            b[i] -= factor * v[i - k];
+        }
+    }
+
+    let r = a.select_rows(&(0..cols).collect::<Vec<usize>>()).unwrap();
+    (r, b)
+}
+
+// Solve R x = b for an upper triangular R
+fn back_substitution(r: &Matrix, b: &[f64]) -> Vec<f64> {
+    let size = r.shape()[0];
+    let mut x = vec![0.; size];
+    for row in (0..size).rev() {
+        let sum: f64 = ((row + 1)..size)
+            .map(|column| r[row][column] * x[column])
+            .sum();
+        x[row] = (b[row] - sum) / r[row][row];
+    }
+    x
+}
+
+// Least squares solution of X b = y, and the diagonal of (X^T X)^-1
+fn least_squares(design: &Matrix, y: &Vector) -> Result<(Vector, Vec<f64>), String> {
+    let [rows, cols] = design.shape();
+    if rows != y.size() {
+        return Err(format!(
+            "Invalid number of observations, got {} and {}",
+            rows,
+            y.size()
+        ));
+    }
+    if rows < cols {
+        return Err(format!(
+            "Expected at least {} observations, got {}",
+            cols, rows
+        ));
+    }
+
+    let (r, qty) = householder(design, y);
+    let largest = (0..cols).map(|k| r[k][k].abs()).fold(0., f64::max);
+    if (0..cols).any(|k| r[k][k].abs() <= f64::EPSILON * rows as f64 * largest) {
+        return Err("Rank deficient design matrix".to_string());
+    }
+    let coefficients = back_substitution(&r, &qty);
+
+    // (X^T X)^-1 = R^-1 R^-T, its diagonal is the squared norm of the rows of R^-1
+    let mut inverse_diagonal = vec![0.; cols];
+    for column in 0..cols {
+        let mut unit = vec![0.; cols];
+        unit[column] = 1.;
+        let inverse_column = back_substitution(&r, &unit);
+        for (row, value) in inverse_column.iter().enumerate() {
+            inverse_diagonal[row] += value * value;
+        }
+    }
+    Ok((Vector::from(coefficients), inverse_diagonal))
+}
+
+// Fit of a design matrix that already contains every term of the model
+fn fit(design: &Matrix, y: &Vector) -> Result<LinearFit, String> {
+    let (coefficients, inverse_diagonal) = least_squares(design, y)?;
+    let [rows, cols] = design.shape();
+
+    let residuals = y.clone() - design.mul_vec(&coefficients);
+    let rss = residuals.dot(&residuals);
+    let mean = y.mean();
+    let tss: f64 = y.iter().map(|value| (value - mean).powi(2)).sum();
+    let variance = if rows > cols {
+        rss / (rows - cols) as f64
+    } else {
+        f64::NAN
+    };
+    let standard_errors = Vector::from(
+        inverse_diagonal
+            .iter()
+            .map(|value| (variance * value).sqrt())
+            .collect::<Vec<f64>>(),
+    );
+
+    Ok(LinearFit {
+        coefficients,
+        residuals,
+        r_squared: if tss == 0. { f64::NAN } else { 1. - rss / tss },
+        standard_errors,
+    })
+}
+
+fn with_intercept(x: &Matrix) -> Result<Matrix, String> {
+    Matrix::hstack(&[&Matrix::from(vec![vec![1.]; x.shape()[0]]), x])
+}
+
+// Ordinary least squares of y on the features of x, with an intercept
+// -- x is a matrix of observations by features
+pub fn fit_linear(x: &Matrix, y: &Vector) -> Result<LinearFit, String> {
+    fit(&with_intercept(x)?, y)
+}
+
+// Coefficients of the polynomial of the given degree closest to the points, from the constant term up
+pub fn polyfit(xs: &Vector, ys: &Vector, degree: usize) -> Result<Vector, String> {
+    if xs.size() != ys.size() {
+        return Err(format!(
+            "Invalid number of points, got {} and {}",
+            xs.size(),
+            ys.size()
+        ));
+    }
+
+    // Vandermonde matrix, each row is [1, x, x^2, ...]
+    let vandermonde = Matrix::from(
+        xs.iter()
+            .map(|x| (0..=degree).map(|power| x.powi(power as i32)).collect())
+            .collect::<Vec<Vec<f64>>>(),
+    );
+    Ok(least_squares(&vandermonde, ys)?.0)
+}
+
+// Least squares with a penalty lambda ||b||^2 on the coefficients of the features, the intercept isn't penalized
+// -- solved as the least squares problem [Xc; sqrt(lambda) I] b = [yc; 0] on the centered data
+pub fn ridge(x: &Matrix, y: &Vector, lambda: f64) -> Result<Vector, String> {
+    if lambda < 0. || lambda.is_nan() {
+        return Err(format!("Invalid regularisation parameter {}", lambda));
+    }
+    let [rows, features] = x.shape();
+    if rows != y.size() {
+        return Err(format!(
+            "Invalid number of observations, got {} and {}",
+            rows,
+            y.size()
+        ));
+    }
+    if rows == 0 {
+        return Err("Expected at least 1 observation".to_string());
+    }
+
+    let x_mean = x.mean_axis(Axis::Columns);
+    let y_mean = y.mean();
+    let centered = Matrix::from(
+        x.iter_rows()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(feature, value)| value - x_mean[feature])
+                    .collect()
+            })
+            .collect::<Vec<Vec<f64>>>(),
+    );
+    let design = Matrix::vstack(&[&centered, &Matrix::identity(features, lambda.sqrt())])?;
+    let target = Vector::from(
+        y.iter()
+            .map(|value| value - y_mean)
+            .chain(vec![0.; features])
+            .collect::<Vec<f64>>(),
+    );
+    let (weights, _) = least_squares(&design, &target)?;
+
+    let intercept = y_mean - x_mean.dot(&weights);
+    Ok(Vector::from(
+        std::iter::once(intercept)
+            .chain(weights.iter().copied())
+            .collect::<Vec<f64>>(),
+    ))
+}
@@ -0,0 +1,627 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{self, BufRead, IsTerminal, Write},
+    process::ExitCode,
+};
+
+use matrix::{
+    cosine::angle_cos, cross_product::cross_product, linear_interpolation::lerp, matrix::Matrix,
+    vector::Vector,
+};
+
+const HELP: &str = "Statements:
+  A = [1 2; 3 4]        Assign a variable, rows are separated by `;`
+  A * B'                Evaluate an expression, the result is stored in `ans`
+  A;                    A trailing `;` hides the result
+
+Operators: + - * / and ' (transpose), with parentheses for grouping
+  A * B is the matrix product, u * v the element-wise product of two vectors
+
+Functions:
+  det(A) inv(A) rank(A) trace(A) transpose(A) rref(A) solve(A, b)
+  dot(u, v) cross(u, v) cos(u, v) norm(u) norm1(u) norminf(u)
+  lerp(a, b, t) identity(n) projection(fov, ratio, near, far)
+
+Commands: help, vars, history, clear, exit";
+
+// Largest size of a created matrix, so that its size * size elements can be allocated
+const MAX_SIZE: usize = 1 << 14;
+
+// * Values
+
+#[derive(Debug, Clone)]
+enum Value {
+    Scalar(f64),
+    Vector(Vector),
+    Matrix(Matrix),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Scalar(value) => write!(f, "{}", value),
+            Value::Vector(vector) => write!(f, "{}", vector),
+            Value::Matrix(matrix) => write!(f, "{}", matrix),
+        }
+    }
+}
+
+impl Value {
+    fn kind(&self) -> &str {
+        match self {
+            Value::Scalar(_) => "scalar",
+            Value::Vector(_) => "vector",
+            Value::Matrix(_) => "matrix",
+        }
+    }
+
+    fn shape(&self) -> [usize; 2] {
+        match self {
+            Value::Scalar(_) => [1, 1],
+            Value::Vector(vector) => [1, vector.size()],
+            Value::Matrix(matrix) => matrix.shape(),
+        }
+    }
+}
+
+// * Tokens
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Identifier(String),
+    // Raw text of a matrix literal, with its brackets
+    Literal(String),
+    Operator(char),
+}
+
+fn tokenize(line: &str) -> Result<Vec<Token>, String> {
+    let characters: Vec<char> = line.chars().collect();
+    let mut tokens = vec![];
+    let mut index = 0;
+    while index < characters.len() {
+        let character = characters[index];
+        if character.is_whitespace() {
+            index += 1;
+        } else if character.is_ascii_digit() || character == '.' {
+            let start = index;
+            while index < characters.len()
+                && (characters[index].is_ascii_alphanumeric() || characters[index] == '.')
+            {
+                // Exponent sign, e.g. `1e-5`
+                if matches!(characters[index], 'e' | 'E')
+                    && index + 1 < characters.len()
+                    && matches!(characters[index + 1], '+' | '-')
+                {
+                    index += 1;
+                }
+                index += 1;
+            }
+            let word: String = characters[start..index].iter().collect();
+            let value = word
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid number '{}' at column {}", word, start + 1))?;
+            tokens.push(Token::Number(value));
+        } else if character.is_alphabetic() || character == '_' {
+            let start = index;
+            while index < characters.len()
+                && (characters[index].is_alphanumeric() || characters[index] == '_')
+            {
+                index += 1;
+            }
+            tokens.push(Token::Identifier(characters[start..index].iter().collect()));
+        } else if character == '[' {
+            let start = index;
+            let mut depth = 0;
+            loop {
+                if index >= characters.len() {
+                    return Err(format!("Unclosed '[' at column {}", start + 1));
+                }
+                match characters[index] {
+                    '[' => depth += 1,
+                    ']' => depth -= 1,
+                    _ => {}
+                }
+                index += 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            tokens.push(Token::Literal(characters[start..index].iter().collect()));
+        } else if "+-*/'(),=".contains(character) {
+            tokens.push(Token::Operator(character));
+            index += 1;
+        } else {
+            return Err(format!(
+                "Unexpected character '{}' at column {}",
+                character,
+                index + 1
+            ));
+        }
+    }
+    Ok(tokens)
+}
+
+// * Expressions
+
+#[derive(Debug)]
+enum Expression {
+    Constant(Value),
+    Variable(String),
+    Negate(Box<Expression>),
+    Transpose(Box<Expression>),
+    Binary(char, Box<Expression>, Box<Expression>),
+    Call(String, Vec<Expression>),
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    current: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.current)
+    }
+
+    fn next_is(&self, operator: char) -> bool {
+        self.peek() == Some(&Token::Operator(operator))
+    }
+
+    fn expect(&mut self, operator: char) -> Result<(), String> {
+        if !self.next_is(operator) {
+            return Err(match self.peek() {
+                Some(token) => format!("Expected '{}', found {}", operator, describe(token)),
+                None => format!("Expected '{}' at the end of the line", operator),
+            });
+        }
+        self.current += 1;
+        Ok(())
+    }
+
+    // expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Result<Expression, String> {
+        let mut left = self.term()?;
+        while self.next_is('+') || self.next_is('-') {
+            let operator = self.operator();
+            let right = self.term()?;
+            left = Expression::Binary(operator, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    // term := unary (('*' | '/') unary)*
+    fn term(&mut self) -> Result<Expression, String> {
+        let mut left = self.unary()?;
+        while self.next_is('*') || self.next_is('/') {
+            let operator = self.operator();
+            let right = self.unary()?;
+            left = Expression::Binary(operator, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    // unary := '-' unary | postfix
+    fn unary(&mut self) -> Result<Expression, String> {
+        if self.next_is('-') {
+            self.current += 1;
+            return Ok(Expression::Negate(Box::new(self.unary()?)));
+        }
+        self.postfix()
+    }
+
+    // postfix := primary '\''*
+    fn postfix(&mut self) -> Result<Expression, String> {
+        let mut expression = self.primary()?;
+        while self.next_is('\'') {
+            self.current += 1;
+            expression = Expression::Transpose(Box::new(expression));
+        }
+        Ok(expression)
+    }
+
+    // primary := number | literal | identifier | identifier '(' arguments ')' | '(' expression ')'
+    fn primary(&mut self) -> Result<Expression, String> {
+        let token = self
+            .peek()
+            .cloned()
+            .ok_or_else(|| "Unexpected end of the line".to_string())?;
+        self.current += 1;
+        match token {
+            Token::Number(value) => Ok(Expression::Constant(Value::Scalar(value))),
+            Token::Literal(text) => {
+                let matrix = text.parse::<Matrix>().map_err(|error| error.to_string())?;
+                // A single row is a vector
+                if matrix.shape()[0] == 1 {
+                    return Ok(Expression::Constant(Value::Vector(Vector::from(
+                        matrix[0].to_vec(),
+                    ))));
+                }
+                Ok(Expression::Constant(Value::Matrix(matrix)))
+            }
+            Token::Identifier(name) => {
+                if !self.next_is('(') {
+                    return Ok(Expression::Variable(name));
+                }
+                self.current += 1;
+                let mut arguments = vec![];
+                if !self.next_is(')') {
+                    arguments.push(self.expression()?);
+                    while self.next_is(',') {
+                        self.current += 1;
+                        arguments.push(self.expression()?);
+                    }
+                }
+                self.expect(')')?;
+                Ok(Expression::Call(name, arguments))
+            }
+            Token::Operator('(') => {
+                let expression = self.expression()?;
+                self.expect(')')?;
+                Ok(expression)
+            }
+            token => Err(format!("Unexpected {}", describe(&token))),
+        }
+    }
+
+    fn operator(&mut self) -> char {
+        let operator = match self.peek() {
+            Some(Token::Operator(operator)) => *operator,
+            _ => unreachable!(),
+        };
+        self.current += 1;
+        operator
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Number(value) => format!("number {}", value),
+        Token::Identifier(name) => format!("'{}'", name),
+        Token::Literal(text) => format!("literal {}", text),
+        Token::Operator(operator) => format!("'{}'", operator),
+    }
+}
+
+// * Evaluation
+
+struct Session {
+    variables: BTreeMap<String, Value>,
+    history: Vec<String>,
+}
+
+fn shape_error(operation: &str, a: &Value, b: &Value) -> String {
+    format!(
+        "Invalid shapes for {}: {} {:?} and {} {:?}",
+        operation,
+        a.kind(),
+        a.shape(),
+        b.kind(),
+        b.shape()
+    )
+}
+
+fn scale(value: Value, factor: f64) -> Value {
+    match value {
+        Value::Scalar(value) => Value::Scalar(value * factor),
+        Value::Vector(vector) => Value::Vector(vector * factor),
+        Value::Matrix(matrix) => Value::Matrix(matrix * factor),
+    }
+}
+
+fn add(operator: char, a: Value, b: Value) -> Result<Value, String> {
+    let b = if operator == '-' { scale(b, -1.) } else { b };
+    match (a, b) {
+        (Value::Scalar(a), Value::Scalar(b)) => Ok(Value::Scalar(a + b)),
+        (Value::Vector(a), Value::Vector(b)) if a.size() == b.size() => Ok(Value::Vector(a + b)),
+        (Value::Matrix(a), Value::Matrix(b)) if a.shape() == b.shape() => Ok(Value::Matrix(a + b)),
+        (a, b) => Err(shape_error("addition", &a, &b)),
+    }
+}
+
+fn multiply(a: Value, b: Value) -> Result<Value, String> {
+    match (a, b) {
+        (Value::Scalar(factor), value) | (value, Value::Scalar(factor)) => Ok(scale(value, factor)),
+        (Value::Vector(a), Value::Vector(b)) if a.size() == b.size() => Ok(Value::Vector(a * b)),
+        (Value::Matrix(a), Value::Vector(b)) if a.shape()[1] == b.size() => {
+            Ok(Value::Vector(a.mul_vec(&b)))
+        }
+        (Value::Matrix(a), Value::Matrix(b)) => {
+            let result = a.mul_mat(&b);
+            if result.shape() == [0, 0] && a.shape() != [0, 0] {
+                return Err(shape_error(
+                    "multiplication",
+                    &Value::Matrix(a),
+                    &Value::Matrix(b),
+                ));
+            }
+            Ok(Value::Matrix(result))
+        }
+        (a, b) => Err(shape_error("multiplication", &a, &b)),
+    }
+}
+
+fn transpose(value: Value) -> Value {
+    match value {
+        Value::Scalar(value) => Value::Scalar(value),
+        Value::Vector(vector) => Value::Matrix(vector.reshape().transpose()),
+        Value::Matrix(matrix) => Value::Matrix(matrix.transpose()),
+    }
+}
+
+fn as_square(name: &str, value: Value) -> Result<Matrix, String> {
+    match value {
+        Value::Scalar(value) => Ok(Matrix::from([[value]])),
+        Value::Matrix(matrix) if matrix.shape()[0] == matrix.shape()[1] => Ok(matrix),
+        value => Err(format!(
+            "{} expects a square matrix, got {} {:?}",
+            name,
+            value.kind(),
+            value.shape()
+        )),
+    }
+}
+
+fn as_matrix(value: Value) -> Matrix {
+    match value {
+        Value::Scalar(value) => Matrix::from([[value]]),
+        Value::Vector(vector) => vector.reshape(),
+        Value::Matrix(matrix) => matrix,
+    }
+}
+
+// Vectors can also be written as a single column
+fn as_vector(name: &str, value: Value) -> Result<Vector, String> {
+    match value {
+        Value::Vector(vector) => Ok(vector),
+        Value::Matrix(matrix) if matrix.shape()[1] == 1 => Ok(Vector::from(
+            matrix.iter_rows().map(|row| row[0]).collect::<Vec<f64>>(),
+        )),
+        value => Err(format!(
+            "{} expects a vector, got {} {:?}",
+            name,
+            value.kind(),
+            value.shape()
+        )),
+    }
+}
+
+fn as_scalar(name: &str, value: Value) -> Result<f64, String> {
+    match value {
+        Value::Scalar(value) => Ok(value),
+        value => Err(format!("{} expects a scalar, got {}", name, value.kind())),
+    }
+}
+
+fn as_size(name: &str, value: Value) -> Result<usize, String> {
+    let value = as_scalar(name, value)?;
+    if value < 0. || value.fract() != 0. {
+        return Err(format!(
+            "{} expects a positive integer, got {}",
+            name, value
+        ));
+    }
+    if value > MAX_SIZE as f64 {
+        return Err(format!(
+            "{} expects a size of at most {}, got {}",
+            name, MAX_SIZE, value
+        ));
+    }
+    Ok(value as usize)
+}
+
+fn call(name: &str, arguments: Vec<Value>) -> Result<Value, String> {
+    let expected = match name {
+        "det" | "inv" | "rank" | "trace" | "transpose" | "rref" | "norm" | "norm1" | "norminf"
+        | "identity" => 1,
+        "solve" | "dot" | "cross" | "cos" => 2,
+        "lerp" => 3,
+        "projection" => 4,
+        _ => return Err(format!("Unknown function '{}'", name)),
+    };
+    if arguments.len() != expected {
+        return Err(format!(
+            "{} expects {} arguments, got {}",
+            name,
+            expected,
+            arguments.len()
+        ));
+    }
+
+    let mut arguments = arguments.into_iter();
+    let mut next = || arguments.next().unwrap();
+    match name {
+        "det" => Ok(Value::Scalar(as_square(name, next())?.determinant())),
+        "inv" => Ok(Value::Matrix(as_square(name, next())?.inverse()?)),
+        "rank" => Ok(Value::Scalar(as_matrix(next()).rank() as f64)),
+        "trace" => Ok(Value::Scalar(as_square(name, next())?.trace())),
+        "transpose" => Ok(transpose(next())),
+        "rref" => Ok(Value::Matrix(as_matrix(next()).reduced_row_echelon())),
+        "norm" => Ok(Value::Scalar(as_vector(name, next())?.norm())),
+        "norm1" => Ok(Value::Scalar(as_vector(name, next())?.norm_1())),
+        "norminf" => Ok(Value::Scalar(as_vector(name, next())?.norm_inf())),
+        "identity" => Ok(Value::Matrix(Matrix::identity(as_size(name, next())?, 1.))),
+        "solve" => {
+            let matrix = as_square(name, next())?;
+            let vector = as_vector(name, next())?;
+            Ok(Value::Vector(matrix.solve(&vector)?))
+        }
+        "dot" | "cross" | "cos" => {
+            let u = as_vector(name, next())?;
+            let v = as_vector(name, next())?;
+            if u.size() != v.size() {
+                return Err(format!(
+                    "{} expects vectors of the same size, got {} and {}",
+                    name,
+                    u.size(),
+                    v.size()
+                ));
+            }
+            match name {
+                "dot" => Ok(Value::Scalar(u.dot(&v))),
+                "cos" => Ok(Value::Scalar(angle_cos(&u, &v))),
+                _ if u.size() != 3 => Err("cross expects vectors of size 3".to_string()),
+                _ => Ok(Value::Vector(cross_product(&u, &v))),
+            }
+        }
+        "lerp" => {
+            let a = next();
+            let b = next();
+            let t = as_scalar(name, next())?;
+            match (a, b) {
+                (Value::Scalar(a), Value::Scalar(b)) => Ok(Value::Scalar(lerp(&a, &b, t))),
+                (Value::Vector(a), Value::Vector(b)) if a.size() == b.size() => {
+                    Ok(Value::Vector(lerp(&a, &b, t)))
+                }
+                (Value::Matrix(a), Value::Matrix(b)) if a.shape() == b.shape() => {
+                    Ok(Value::Matrix(lerp(&a, &b, t)))
+                }
+                (a, b) => Err(shape_error("lerp", &a, &b)),
+            }
+        }
+        "projection" => {
+            let values = [next(), next(), next(), next()]
+                .into_iter()
+                .map(|value| as_scalar(name, value))
+                .collect::<Result<Vec<f64>, String>>()?;
+            Ok(Value::Matrix(Matrix::projection(
+                values[0], values[1], values[2], values[3],
+            )))
+        }
+        _ => unreachable!(),
+    }
+}
+
+impl Session {
+    fn evaluate(&self, expression: Expression) -> Result<Value, String> {
+        match expression {
+            Expression::Constant(value) => Ok(value),
+            Expression::Variable(name) => self
+                .variables
+                .get(&name)
+                .cloned()
+                .ok_or_else(|| format!("Unknown variable '{}'", name)),
+            Expression::Negate(expression) => Ok(scale(self.evaluate(*expression)?, -1.)),
+            Expression::Transpose(expression) => Ok(transpose(self.evaluate(*expression)?)),
+            Expression::Binary(operator, a, b) => {
+                let a = self.evaluate(*a)?;
+                let b = self.evaluate(*b)?;
+                match operator {
+                    '+' | '-' => add(operator, a, b),
+                    '*' => multiply(a, b),
+                    '/' => match b {
+                        Value::Scalar(b) if b != 0. => Ok(scale(a, 1. / b)),
+                        Value::Scalar(_) => Err("Division by zero".to_string()),
+                        b => Err(format!("Can't divide by a {}", b.kind())),
+                    },
+                    _ => unreachable!(),
+                }
+            }
+            Expression::Call(name, arguments) => {
+                let arguments = arguments
+                    .into_iter()
+                    .map(|argument| self.evaluate(argument))
+                    .collect::<Result<Vec<Value>, String>>()?;
+                call(&name, arguments)
+            }
+        }
+    }
+
+    // Execute a line and return the text to display
+    fn execute(&mut self, line: &str) -> Result<Option<String>, String> {
+        let (line, quiet) = match line.trim().strip_suffix(';') {
+            Some(line) => (line, true),
+            None => (line.trim(), false),
+        };
+
+        let mut tokens = tokenize(line)?;
+        let name = match tokens.as_slice() {
+            [Token::Identifier(name), Token::Operator('='), ..] => {
+                let name = name.clone();
+                tokens.drain(..2);
+                name
+            }
+            _ => "ans".to_string(),
+        };
+
+        let mut parser = Parser { tokens, current: 0 };
+        let expression = parser.expression()?;
+        if let Some(token) = parser.peek() {
+            return Err(format!("Unexpected {}", describe(token)));
+        }
+
+        let value = self.evaluate(expression)?;
+        let text = match &value {
+            Value::Scalar(_) => format!("{} = {}", name, value),
+            _ => format!("{} =\n{}", name, value),
+        };
+        self.variables.insert(name, value);
+        Ok(if quiet { None } else { Some(text) })
+    }
+}
+
+pub fn run() -> ExitCode {
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let mut session = Session {
+        variables: BTreeMap::new(),
+        history: vec![],
+    };
+    let mut failed = false;
+
+    if interactive {
+        println!("Interactive matrix calculator, type `help` for the syntax and `exit` to quit.");
+    }
+    let mut lines = stdin.lock().lines();
+    loop {
+        if interactive {
+            print!(">> ");
+            let _ = io::stdout().flush();
+        }
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(error)) => {
+                eprintln!("error: {}", error);
+                return ExitCode::FAILURE;
+            }
+            None => break,
+        };
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        session.history.push(trimmed.to_string());
+        match trimmed {
+            "exit" | "quit" => break,
+            "help" => println!("{}", HELP),
+            "clear" => session.variables.clear(),
+            "vars" => {
+                for (name, value) in session.variables.iter() {
+                    println!("{}: {} {:?}", name, value.kind(), value.shape());
+                }
+            }
+            "history" => {
+                for (index, line) in session.history.iter().enumerate() {
+                    println!("{:>4}  {}", index + 1, line);
+                }
+            }
+            _ => match session.execute(trimmed) {
+                Ok(Some(text)) => println!("{}", text),
+                Ok(None) => {}
+                Err(error) => {
+                    failed = true;
+                    eprintln!("error: {}", error);
+                }
+            },
+        }
+    }
+
+    // Scripts fail if any of their lines failed
+    if failed && !interactive {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
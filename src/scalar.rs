@@ -0,0 +1,78 @@
+use crate::matrix::Matrix;
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+// Value that can be stored in a Matrix or a Vector
+pub trait Scalar: Clone + PartialEq + Debug {
+    // Identity of the addition
+    fn zero() -> Self;
+
+    // Identity of the multiplication
+    fn one() -> Self;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    // Only used to display the sign of a coefficient, unordered scalars are never negative
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    // Check if the value is a better pivot than the other one during an elimination
+    // -- exact scalars only need a non-zero pivot, floating points prefer the largest magnitude
+    fn is_better_pivot(&self, other: &Self) -> bool {
+        !self.is_zero() && other.is_zero()
+    }
+
+    // Check if a pivot is zero compared to the largest element of the matrix, used by `is_invertible`
+    // -- exact scalars only reject zero, floating points use `Matrix::SINGULAR_TOLERANCE`
+    fn is_negligible(&self, _largest: &Self) -> bool {
+        self.is_zero()
+    }
+}
+
+// Scalar with an addition and a multiplication, `zero` and `one` are their identities
+// -- the operations don't need to be the usual ones, e.g. (min, +) for shortest paths
+pub trait Semiring: Scalar + Add<Output = Self> + Mul<Output = Self> {}
+
+// Semiring where the addition is idempotent (a + a = a), which makes the closure of a matrix finite
+pub trait Idempotent: Semiring {}
+
+// Semiring with a subtraction
+pub trait Ring: Semiring + Sub<Output = Self> + Neg<Output = Self> {}
+
+// Ring where each non-zero value can be divided
+pub trait Field: Ring + Div<Output = Self> {}
+
+// * f64
+
+impl Scalar for f64 {
+    fn zero() -> Self {
+        0.
+    }
+
+    fn one() -> Self {
+        1.
+    }
+
+    fn is_negative(&self) -> bool {
+        *self < 0.
+    }
+
+    fn is_better_pivot(&self, other: &Self) -> bool {
+        self.abs() > other.abs()
+    }
+
+    fn is_negligible(&self, largest: &Self) -> bool {
+        self.abs() <= Matrix::SINGULAR_TOLERANCE * largest.abs()
+    }
+}
+
+impl Semiring for f64 {}
+
+impl Ring for f64 {}
+
+impl Field for f64 {}
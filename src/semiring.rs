@@ -0,0 +1,166 @@
+use std::{
+    fmt,
+    ops::{Add, Mul},
+};
+
+use crate::scalar::{Idempotent, Scalar, Semiring};
+
+// Boolean semiring (or, and), a product of adjacency matrices tells if a path exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Boolean(pub bool);
+
+// Tropical semiring (min, +), a product of weight matrices gives the shortest paths
+// -- a missing edge is represented by +∞
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MinPlus(pub f64);
+
+// Tropical semiring (max, +), a product of duration matrices gives the longest paths, as in scheduling
+// -- a missing edge is represented by -∞
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MaxPlus(pub f64);
+
+// * Boolean
+
+impl Add for Boolean {
+    type Output = Boolean;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Boolean(self.0 || rhs.0)
+    }
+}
+
+impl Mul for Boolean {
+    type Output = Boolean;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Boolean(self.0 && rhs.0)
+    }
+}
+
+// Displayed as 1 or 0 to keep matrices aligned
+impl fmt::Display for Boolean {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0 as u8)
+    }
+}
+
+impl From<bool> for Boolean {
+    fn from(value: bool) -> Self {
+        Boolean(value)
+    }
+}
+
+impl Scalar for Boolean {
+    fn zero() -> Self {
+        Boolean(false)
+    }
+
+    fn one() -> Self {
+        Boolean(true)
+    }
+}
+
+impl Semiring for Boolean {}
+
+impl Idempotent for Boolean {}
+
+// * MinPlus
+
+impl Add for MinPlus {
+    type Output = MinPlus;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MinPlus(self.0.min(rhs.0))
+    }
+}
+
+// The multiplication of the semiring is the usual addition
+impl Mul for MinPlus {
+    type Output = MinPlus;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        MinPlus(self.0 + rhs.0)
+    }
+}
+
+impl fmt::Display for MinPlus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_tropical(f, self.0)
+    }
+}
+
+impl From<f64> for MinPlus {
+    fn from(value: f64) -> Self {
+        MinPlus(value)
+    }
+}
+
+impl Scalar for MinPlus {
+    fn zero() -> Self {
+        MinPlus(f64::INFINITY)
+    }
+
+    fn one() -> Self {
+        MinPlus(0.)
+    }
+}
+
+impl Semiring for MinPlus {}
+
+impl Idempotent for MinPlus {}
+
+// * MaxPlus
+
+impl Add for MaxPlus {
+    type Output = MaxPlus;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MaxPlus(self.0.max(rhs.0))
+    }
+}
+
+// The multiplication of the semiring is the usual addition
+impl Mul for MaxPlus {
+    type Output = MaxPlus;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        MaxPlus(self.0 + rhs.0)
+    }
+}
+
+impl fmt::Display for MaxPlus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_tropical(f, self.0)
+    }
+}
+
+impl From<f64> for MaxPlus {
+    fn from(value: f64) -> Self {
+        MaxPlus(value)
+    }
+}
+
+impl Scalar for MaxPlus {
+    fn zero() -> Self {
+        MaxPlus(f64::NEG_INFINITY)
+    }
+
+    fn one() -> Self {
+        MaxPlus(0.)
+    }
+}
+
+impl Semiring for MaxPlus {}
+
+impl Idempotent for MaxPlus {}
+
+// Infinite values are displayed as `∞` and `-∞`, the precision of the formatter is kept otherwise
+fn write_tropical(f: &mut fmt::Formatter, value: f64) -> fmt::Result {
+    if value.is_infinite() {
+        let sign = if value < 0. { "-" } else { "" };
+        return write!(f, "{}∞", sign);
+    }
+    fmt::Display::fmt(&value, f)
+}
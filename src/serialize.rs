@@ -0,0 +1,46 @@
+// Serde support, enabled with the `serde` feature
+// Vectors are serialized as a sequence of values and matrices as a sequence of rows
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{matrix::Matrix, vector::Vector};
+
+impl Serialize for Vector {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.all().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vector::from(Vec::<f64>::deserialize(deserializer)?))
+    }
+}
+
+impl Serialize for Matrix {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.all().serialize(serializer)
+    }
+}
+
+// Rows must all have the same length
+impl<'de> Deserialize<'de> for Matrix {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Vec<f64>>::deserialize(deserializer)?;
+        if let Some(first) = rows.first() {
+            let columns = first.len();
+            if let Some((index, row)) = rows
+                .iter()
+                .enumerate()
+                .find(|(_, row)| row.len() != columns)
+            {
+                return Err(D::Error::custom(format!(
+                    "Row {} has {} columns, expected {}",
+                    index + 1,
+                    row.len(),
+                    columns
+                )));
+            }
+        }
+        Ok(Matrix::from(rows))
+    }
+}
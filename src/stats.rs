@@ -0,0 +1,155 @@
+use crate::{matrix::Matrix, reduction::Axis, vector::Vector};
+
+// Data is given as a matrix of observations by features, one observation in each row
+
+// Principal component analysis of a data set
+#[derive(Debug, Clone)]
+pub struct Pca {
+    // Mean of each feature, removed before the projection
+    pub mean: Vector,
+    // Principal axes as rows, of shape [k, features]
+    pub components: Matrix,
+    // Variance of the data along each of the components
+    pub explained_variance: Vector,
+    // Fraction of the total variance of the data along each of the components
+    pub explained_variance_ratio: Vector,
+    // Data projected on the components, of shape [observations, k]
+    pub projected: Matrix,
+}
+
+fn observations(data: &Matrix) -> Result<usize, String> {
+    let observations = data.shape()[0];
+    if observations < 2 {
+        return Err(format!(
+            "Expected at least 2 observations, got {}",
+            observations
+        ));
+    }
+    Ok(observations)
+}
+
+// Subtract the mean of each column
+pub fn center(data: &Matrix) -> Matrix {
+    let mean = data.mean_axis(Axis::Columns);
+    let [rows, cols] = data.shape();
+    let mut result = data.clone();
+    for row in 0..rows {
+        for column in 0..cols {
+            result[row][column] -= mean[column];
+        }
+    }
+    result
+}
+
+// Center each column and divide it by its sample standard deviation
+pub fn standardize(data: &Matrix) -> Result<Matrix, String> {
+    observations(data)?;
+    let std_dev = data.std_dev_axis(Axis::Columns);
+    if let Some(feature) = std_dev.iter().position(|value| *value == 0.) {
+        return Err(format!("Feature {} is constant", feature));
+    }
+
+    let [rows, cols] = data.shape();
+    let mut result = center(data);
+    for row in 0..rows {
+        for column in 0..cols {
+            result[row][column] /= std_dev[column];
+        }
+    }
+    Ok(result)
+}
+
+// Sample covariance matrix of the features, Xc^T Xc / (n - 1) with Xc the centered data
+pub fn covariance(data: &Matrix) -> Result<Matrix, String> {
+    let observations = observations(data)?;
+    let centered = center(data);
+    Ok(centered.transpose().mul_mat(&centered) * (1. / (observations - 1) as f64))
+}
+
+// Pearson correlation matrix of the features
+pub fn correlation(data: &Matrix) -> Result<Matrix, String> {
+    let mut result = covariance(&standardize(data)?)?;
+    // Remove the rounding errors of the diagonal
+    for feature in 0..result.shape()[0] {
+        result[feature][feature] = 1.;
+    }
+    Ok(result)
+}
+
+// Project the data on the k eigenvectors of its covariance matrix with the largest eigenvalues
+pub fn pca(data: &Matrix, k: usize) -> Result<Pca, String> {
+    let features = data.shape()[1];
+    if k == 0 || k > features {
+        return Err(format!(
+            "Invalid number of components {} for {} features",
+            k, features
+        ));
+    }
+    if !data.iter().all(|value| value.is_finite()) {
+        return Err("PCA expects finite values".to_string());
+    }
+
+    let (values, vectors) = covariance(data)?.symmetric_eigen()?;
+    let total: f64 = values.iter().map(|value| value.max(0.)).sum();
+    if total == 0. {
+        return Err("Data has no variance".to_string());
+    }
+
+    let mut components = vectors
+        .transpose()
+        .select_rows(&(0..k).collect::<Vec<usize>>())?;
+    // The sign of an eigenvector is arbitrary, make the largest coordinate of each positive
+    for component in 0..k {
+        let largest = (0..features)
+            .map(|feature| components[component][feature])
+            .fold(0., |largest: f64, value| {
+                if value.abs() > largest.abs() {
+                    value
+                } else {
+                    largest
+                }
+            });
+        if largest < 0. {
+            for feature in 0..features {
+                components[component][feature] = -components[component][feature];
+            }
+        }
+    }
+
+    let explained_variance = Vector::from(
+        (0..k)
+            .map(|component| values[component].max(0.))
+            .collect::<Vec<f64>>(),
+    );
+    let explained_variance_ratio = explained_variance.clone() * (1. / total);
+    let projected = center(data).mul_mat(&components.transpose());
+    Ok(Pca {
+        mean: data.mean_axis(Axis::Columns),
+        components,
+        explained_variance,
+        explained_variance_ratio,
+        projected,
+    })
+}
+
+impl Pca {
+    // Project new observations on the components
+    pub fn transform(&self, data: &Matrix) -> Result<Matrix, String> {
+        let [rows, cols] = data.shape();
+        if cols != self.mean.size() {
+            return Err(format!(
+                "Invalid number of features, got {} expected {}",
+                cols,
+                self.mean.size()
+            ));
+        }
+
+        let mut centered = data.clone();
+        for row in 0..rows {
+            for column in 0..cols {
+                centered[row][column] -= self.mean[column];
+            }
+        }
+        Ok(centered.mul_mat(&self.components.transpose()))
+    }
+}
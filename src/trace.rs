@@ -0,0 +1,192 @@
+use std::fmt;
+
+use crate::{
+    export::{self, ExportOptions, Latex},
+    matrix::Matrix,
+    scalar::Ring,
+};
+
+// Elementary row operation, rows are 0-indexed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operation<K = f64> {
+    // Swap the two rows
+    Swap(usize, usize),
+    // Multiply the row by the factor
+    Scale(usize, K),
+    // Add the second row multiplied by the factor to the first row
+    AddMultiple(usize, usize, K),
+    // Bareiss update of the target row with the pivot row:
+    // target = (scale * target - factor * pivot) / divisor
+    FractionFree {
+        target: usize,
+        pivot: usize,
+        scale: K,
+        factor: K,
+        divisor: K,
+    },
+}
+
+// A row operation and the matrix after it has been applied
+#[derive(Debug, Clone)]
+pub struct Step<K = f64> {
+    pub operation: Operation<K>,
+    pub matrix: Matrix<K>,
+}
+
+// Record a step if the steps are traced, the matrix is only built in this case
+pub(crate) fn record<K>(
+    steps: &mut Option<&mut Vec<Step<K>>>,
+    operation: Operation<K>,
+    matrix: impl FnOnce() -> Matrix<K>,
+) {
+    if let Some(steps) = steps.as_deref_mut() {
+        steps.push(Step {
+            operation,
+            matrix: matrix(),
+        });
+    }
+}
+
+// Term of a linear combination of rows, e.g. `+ 2·R1` or `- R1`
+fn term<K: Ring>(factor: K, row: &str, format: impl Fn(&K) -> String, times: &str) -> String {
+    let (sign, factor) = if factor.is_negative() {
+        ("-", -factor)
+    } else {
+        ("+", factor)
+    };
+    if factor == K::one() {
+        format!("{} {}", sign, row)
+    } else {
+        format!("{} {}{}{}", sign, format(&factor), times, row)
+    }
+}
+
+impl<K: Ring> Operation<K> {
+    fn render(
+        &self,
+        row: impl Fn(usize) -> String,
+        format: impl Fn(&K) -> String,
+        arrow: &str,
+        swap: &str,
+        times: &str,
+    ) -> String {
+        match self {
+            Operation::Swap(a, b) => format!("{} {} {}", row(*a), swap, row(*b)),
+            Operation::Scale(target, factor) => format!(
+                "{} {} {}{}{}",
+                row(*target),
+                arrow,
+                format(factor),
+                times,
+                row(*target)
+            ),
+            Operation::AddMultiple(target, source, factor) => format!(
+                "{} {} {} {}",
+                row(*target),
+                arrow,
+                row(*target),
+                term(factor.clone(), &row(*source), &format, times)
+            ),
+            Operation::FractionFree {
+                target,
+                pivot,
+                scale,
+                factor,
+                divisor,
+            } => {
+                let combination = format!(
+                    "{}{}{} {}",
+                    format(scale),
+                    times,
+                    row(*target),
+                    term(-factor.clone(), &row(*pivot), &format, times)
+                );
+                if *divisor == K::one() {
+                    format!("{} {} {}", row(*target), arrow, combination)
+                } else {
+                    format!(
+                        "{} {} ({}) / {}",
+                        row(*target),
+                        arrow,
+                        combination,
+                        format(divisor)
+                    )
+                }
+            }
+        }
+    }
+}
+
+impl<K: Ring + Latex> Operation<K> {
+    // LaTeX representation of the operation, e.g. `R_{2} \leftarrow R_{2} - 3R_{1}`
+    pub fn to_latex(&self, options: &ExportOptions) -> String {
+        self.render(
+            |row| format!("R_{{{}}}", row + 1),
+            |value| value.to_latex(options),
+            "\\leftarrow",
+            "\\leftrightarrow",
+            "",
+        )
+    }
+}
+
+// Rows are displayed 1-indexed, e.g. `R2 ← R2 - 3·R1`
+impl<K: Ring + fmt::Display> fmt::Display for Operation<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = self.render(
+            |row| format!("R{}", row + 1),
+            |value| format!("{}", value),
+            "←",
+            "↔",
+            "·",
+        );
+        write!(f, "{}", text)
+    }
+}
+
+// The operation followed by the matrix, the formatter options are used for the matrix
+impl<K: Ring + fmt::Display> fmt::Display for Step<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.operation)?;
+        fmt::Display::fmt(&self.matrix, f)
+    }
+}
+
+impl<K: Ring + Latex> Step<K> {
+    // The matrix preceded by the operation on an arrow
+    pub fn to_latex(&self, options: &ExportOptions) -> String {
+        format!(
+            "\\xrightarrow{{{}}} {}",
+            self.operation.to_latex(options),
+            export::latex(&self.matrix.row_slices(), options)
+        )
+    }
+}
+
+// Display all of the steps, separated by an empty line
+pub fn steps_to_text<K: Ring + fmt::Display>(steps: &[Step<K>]) -> String {
+    steps
+        .iter()
+        .map(|step| step.to_string())
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+// LaTeX `align*` environment with the initial matrix and one line per step
+pub fn steps_to_latex<K: Ring + Latex>(
+    initial: &Matrix<K>,
+    steps: &[Step<K>],
+    options: &ExportOptions,
+) -> String {
+    let mut lines = vec![format!(
+        "& {}",
+        export::latex(&initial.row_slices(), options)
+    )];
+    for step in steps {
+        lines.push(format!("& {}", step.to_latex(options)));
+    }
+    format!(
+        "\\begin{{align*}}\n{}\n\\end{{align*}}",
+        lines.join(" \\\\\n")
+    )
+}
@@ -1,30 +1,46 @@
-use crate::{linear_interpolation::Lerp, matrix::Matrix};
+use crate::{
+    display,
+    export::{self, ExportOptions},
+    io::{
+        self,
+        binary::{self, BinaryOptions},
+    },
+    linear_interpolation::Lerp,
+    matrix::Matrix,
+    parse::{self, ParseError},
+    scalar::{Ring, Scalar, Semiring},
+};
 use std::{
     fmt::{self, Debug},
+    io::{Read, Write},
     ops::{Add, Index, IndexMut, Mul, Sub},
-    slice::Iter,
+    slice::{Chunks, Iter, IterMut},
+    str::FromStr,
+    vec,
 };
 
 #[derive(Debug)]
-pub struct Vector {
-    elements: Vec<f64>,
+pub struct Vector<K = f64> {
+    elements: Vec<K>,
 }
 
-impl fmt::Display for Vector {
+// Display the vector as an aligned row, honouring the precision and width of the formatter
+// -- `{:#}` displays the vector without padding instead
+impl<K: fmt::Display> fmt::Display for Vector<K> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self.elements)
+        display::write_rows(f, &[self.elements.as_slice()])
     }
 }
 
-impl Default for Vector {
+impl<K: Scalar> Default for Vector<K> {
     fn default() -> Self {
-        Self::new(0)
+        Self::zeros(0)
     }
 }
 
 // * Clone
 
-impl Clone for Vector {
+impl<K: Clone> Clone for Vector<K> {
     fn clone_from(&mut self, source: &Self) {
         *self = source.clone()
     }
@@ -38,77 +54,77 @@ impl Clone for Vector {
 
 // * Index access
 
-impl Index<usize> for Vector {
-    type Output = f64;
+impl<K> Index<usize> for Vector<K> {
+    type Output = K;
 
-    fn index(&self, i: usize) -> &f64 {
+    fn index(&self, i: usize) -> &K {
         &self.elements[i]
     }
 }
 
-impl IndexMut<usize> for Vector {
-    fn index_mut(&mut self, i: usize) -> &mut f64 {
+impl<K> IndexMut<usize> for Vector<K> {
+    fn index_mut(&mut self, i: usize) -> &mut K {
         &mut self.elements[i]
     }
 }
 
 // * Operations
 
-impl Add for Vector {
-    type Output = Vector;
+impl<K: Semiring> Add for Vector<K> {
+    type Output = Vector<K>;
 
     fn add(self, rhs: Self) -> Self::Output {
         if self.size() == rhs.size() {
-            let mut vector = Vector::new(self.size());
+            let mut vector = Vector::zeros(self.size());
             for index in 0..self.size() {
-                vector[index] = self[index] + rhs[index];
+                vector[index] = self[index].clone() + rhs[index].clone();
             }
             vector
         } else {
-            Vector::new(0)
+            Vector::zeros(0)
         }
     }
 }
 
-impl Sub for Vector {
-    type Output = Vector;
+impl<K: Ring> Sub for Vector<K> {
+    type Output = Vector<K>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         if self.size() == rhs.size() {
-            let mut vector = Vector::new(self.size());
+            let mut vector = Vector::zeros(self.size());
             for index in 0..self.size() {
-                vector[index] = self[index] - rhs[index];
+                vector[index] = self[index].clone() - rhs[index].clone();
             }
             vector
         } else {
-            Vector::new(0)
+            Vector::zeros(0)
         }
     }
 }
 
-impl Mul for Vector {
-    type Output = Vector;
+impl<K: Semiring> Mul for Vector<K> {
+    type Output = Vector<K>;
 
     fn mul(self, rhs: Self) -> Self::Output {
         if self.size() == rhs.size() {
-            let mut vector = Vector::new(self.size());
+            let mut vector = Vector::zeros(self.size());
             for index in 0..self.size() {
-                vector[index] = self[index] * rhs[index];
+                vector[index] = self[index].clone() * rhs[index].clone();
             }
             vector
         } else {
-            Vector::new(0)
+            Vector::zeros(0)
         }
     }
 }
 
-impl Mul<f64> for Vector {
-    type Output = Vector;
+impl<K: Semiring> Mul<K> for Vector<K> {
+    type Output = Vector<K>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        let mut vector = Vector::new(self.size());
+    fn mul(self, rhs: K) -> Self::Output {
+        let mut vector = Vector::zeros(self.size());
         for index in 0..self.size() {
-            vector[index] = self[index] * rhs;
+            vector[index] = self[index].clone() * rhs.clone();
         }
         vector
     }
@@ -116,22 +132,72 @@ impl Mul<f64> for Vector {
 
 // *> From
 
-impl From<Vec<f64>> for Vector {
-    fn from(vec: Vec<f64>) -> Self {
+impl<K> From<Vec<K>> for Vector<K> {
+    fn from(vec: Vec<K>) -> Self {
         Vector { elements: vec }
     }
 }
 
-impl<const N: usize> From<[f64; N]> for Vector {
-    fn from(slice: [f64; N]) -> Self {
+impl<K: Clone, const N: usize> From<[K; N]> for Vector<K> {
+    fn from(slice: [K; N]) -> Self {
         Vector {
             elements: slice.to_vec(),
         }
     }
 }
 
+// Parse a vector written as a single row or a single column, e.g. `[1 2 3]` or `1, 2, 3`
+impl FromStr for Vector {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Vector {
+            elements: parse::parse_elements(s)?,
+        })
+    }
+}
+
+impl<K> FromIterator<K> for Vector<K> {
+    fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
+        Vector {
+            elements: iter.into_iter().collect(),
+        }
+    }
+}
+
 // *< From
 
+// *> Iterator
+
+impl<K> IntoIterator for Vector<K> {
+    type Item = K;
+    type IntoIter = vec::IntoIter<K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.into_iter()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a Vector<K> {
+    type Item = &'a K;
+    type IntoIter = Iter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a mut Vector<K> {
+    type Item = &'a mut K;
+    type IntoIter = IterMut<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter_mut()
+    }
+}
+
+// *< Iterator
+
 // * Lerp
 
 impl Lerp for Vector {
@@ -150,10 +216,11 @@ impl Lerp for Vector {
 
 // * Vector
 
-impl Vector {
-    pub fn new(size: usize) -> Vector {
+impl<K: Scalar> Vector<K> {
+    // Vector of the given size filled with zeros, for any scalar
+    pub fn zeros(size: usize) -> Vector<K> {
         Vector {
-            elements: vec![f64::default(); size],
+            elements: vec![K::zero(); size],
         }
     }
 
@@ -178,113 +245,153 @@ impl Vector {
 
     // Transform the vector to a Matrix, keeping it's current size
     #[allow(dead_code)]
-    pub fn reshape(&self) -> Matrix {
+    pub fn reshape(&self) -> Matrix<K> {
         Matrix::from(self.elements.clone())
     }
 
+    // Matrix with a single column, of shape [size, 1]
+    pub fn to_column_matrix(&self) -> Matrix<K> {
+        Matrix::from_fn([self.size(), 1], |row, _| self[row].clone())
+    }
+
     #[allow(dead_code)]
-    pub fn all(&self) -> &Vec<f64> {
+    pub fn all(&self) -> &Vec<K> {
         &self.elements
     }
 
     // Fill the vector with a given value
     #[allow(dead_code)]
-    pub fn fill(&mut self, value: f64) {
+    pub fn fill(&mut self, value: K) {
         for element in self.elements.iter_mut() {
-            *element = value
+            *element = value.clone()
         }
     }
 
-    // Create an iterator in the direction of the row of the vector
-    #[allow(dead_code)]
-    pub fn iter_rows(&self) -> Iter<'_, f64> {
+    pub fn iter(&self) -> Iter<'_, K> {
         self.elements.iter()
     }
 
+    pub fn iter_mut(&mut self) -> IterMut<'_, K> {
+        self.elements.iter_mut()
+    }
+
+    // Create an iterator in the direction of the rows of the vector
+    // -- the vector is a single row, which is yielded as a slice unless the vector is empty
+    #[allow(dead_code)]
+    pub fn iter_rows(&self) -> Chunks<'_, K> {
+        self.elements.chunks(self.size().max(1))
+    }
+
     // Create an iterator in the direction of the columns of the vector
+    // -- each column contains a single element
     #[allow(dead_code)]
-    pub fn iter_cols(&self) -> Iter<'_, f64> {
+    pub fn iter_cols(&self) -> Iter<'_, K> {
         self.elements.iter()
     }
 
-    // Apply a function on each of the elements of the vector and return a new vector with the function applied
-    #[allow(dead_code)]
-    pub fn map(&self, callback: fn(index: usize, value: f64) -> f64) -> Result<Vector, String> {
-        let size = self.size();
-        let mut new_vector = Vector::new(size);
-        for column in 0..size {
-            new_vector[column] = callback(column, self[column]);
-        }
+    // * Closures
 
-        Ok(new_vector)
+    // New vector with the function applied on each of the elements
+    pub fn map<L>(&self, callback: impl Fn(&K) -> L) -> Vector<L> {
+        Vector {
+            elements: self.elements.iter().map(callback).collect(),
+        }
     }
 
-    // Apply a function on each of the elements of two vectors and return a new vector with the function applied
-    #[allow(dead_code)]
-    pub fn map_tuple<'a>(
-        a: &'a Vector,
-        b: &'a Vector,
-        callback: fn(index: usize, a: f64, b: f64) -> f64,
-    ) -> Result<Vector, String> {
-        let a_size = a.size();
-        if a_size != b.size() {
-            return Err(format!("Invalid sizes {:?} and {:?}", a_size, b.size()));
+    // Same as `map`, with the index of each element
+    pub fn map_indexed<L>(&self, callback: impl Fn(usize, &K) -> L) -> Vector<L> {
+        Vector {
+            elements: self
+                .elements
+                .iter()
+                .enumerate()
+                .map(|(index, value)| callback(index, value))
+                .collect(),
         }
+    }
 
-        let mut new_vector = Vector::new(a_size);
-        for column in 0..a_size {
-            new_vector[column] = callback(column, a[column], b[column]);
+    // New vector with the function applied on the elements at the same index in both vectors
+    pub fn zip_map<M: Scalar, L>(
+        &self,
+        other: &Vector<M>,
+        callback: impl Fn(&K, &M) -> L,
+    ) -> Result<Vector<L>, String> {
+        if self.size() != other.size() {
+            return Err(format!(
+                "Invalid sizes {:?} and {:?}",
+                self.size(),
+                other.size()
+            ));
         }
+        Ok(Vector {
+            elements: self
+                .elements
+                .iter()
+                .zip(other.elements.iter())
+                .map(|(a, b)| callback(a, b))
+                .collect(),
+        })
+    }
 
-        Ok(new_vector)
+    // Update each of the elements in place
+    pub fn apply_mut(&mut self, callback: impl FnMut(&mut K)) {
+        self.elements.iter_mut().for_each(callback);
     }
 
-    // * Subject functions
+    // Accumulate the elements, in order
+    pub fn fold<B>(&self, init: B, callback: impl FnMut(B, &K) -> B) -> B {
+        self.elements.iter().fold(init, callback)
+    }
 
-    pub fn add(&mut self, b: &Vector) {
-        let size = self.size();
-        if size != b.size() {
-            // return Err(format!("Invalid sizes {:?} and {:?}", size, b.size()));
-            return;
-        }
+    // Call the function on each of the elements, in order
+    pub fn for_each(&self, callback: impl FnMut(&K)) {
+        self.elements.iter().for_each(callback);
+    }
+}
 
-        for column in 0..size {
-            self[column] += b[column]
-        }
+impl Vector {
+    pub fn new(size: usize) -> Vector {
+        Vector::zeros(size)
     }
 
-    pub fn sub(&mut self, b: &Vector) {
-        let size = self.size();
-        if size != b.size() {
-            // return Err(format!("Invalid sizes {:?} and {:?}", size, b.size()));
-            return;
-        }
+    // * Utility functions
 
-        for column in 0..size {
-            self[column] -= b[column]
-        }
+    // * Export functions
+
+    // LaTeX `bmatrix` environment, as a single row
+    pub fn to_latex(&self, options: &ExportOptions) -> String {
+        export::latex(&[self.elements.as_slice()], options)
     }
 
-    pub fn scl(&mut self, value: f64) {
-        let size = self.size();
-        for column in 0..size {
-            self[column] *= value
-        }
+    // Markdown table with a single row, with the index of each column as the header
+    pub fn to_markdown(&self, options: &ExportOptions) -> String {
+        export::markdown(&[self.elements.as_slice()], options)
     }
 
-    pub fn dot(&self, b: &Vector) -> f64 {
-        let size = self.size();
-        if size != b.size() {
-            return f64::default();
-        }
+    // MATLAB row vector literal, as `[a b c]`
+    pub fn to_matlab(&self, options: &ExportOptions) -> String {
+        export::matlab(&[self.elements.as_slice()], options)
+    }
 
-        let mut result = f64::default();
-        for index in 0..size {
-            result += self[index] * b[index];
-        }
-        result
+    // NumPy one-dimensional literal, as `np.array([a, b, c], dtype=float)`
+    pub fn to_numpy(&self, options: &ExportOptions) -> String {
+        export::numpy_vector(&self.elements, options)
     }
 
+    // * Binary serialization
+
+    // Write the vector as a self-describing binary record
+    pub fn write_to<W: Write>(&self, writer: W, options: &BinaryOptions) -> Result<(), io::Error> {
+        binary::write_vector(writer, self, options)
+    }
+
+    // Read a vector written by `write_to`
+    pub fn read_from<R: Read>(reader: R) -> Result<Vector, io::Error> {
+        binary::read_vector(reader)
+    }
+
+    // * Subject functions
+
     pub fn norm_1(&self) -> f64 {
         let mut result = 0.;
         for index in 0..self.size() {
@@ -312,3 +419,53 @@ impl Vector {
         result
     }
 }
+
+impl<K: Semiring> Vector<K> {
+    // * Subject functions
+
+    pub fn add(&mut self, b: &Vector<K>) {
+        let size = self.size();
+        if size != b.size() {
+            // return Err(format!("Invalid sizes {:?} and {:?}", size, b.size()));
+            return;
+        }
+
+        for column in 0..size {
+            self[column] = self[column].clone() + b[column].clone()
+        }
+    }
+
+    pub fn scl(&mut self, value: K) {
+        let size = self.size();
+        for column in 0..size {
+            self[column] = self[column].clone() * value.clone()
+        }
+    }
+
+    pub fn dot(&self, b: &Vector<K>) -> K {
+        let size = self.size();
+        if size != b.size() {
+            return K::zero();
+        }
+
+        let mut result = K::zero();
+        for index in 0..size {
+            result = result + self[index].clone() * b[index].clone();
+        }
+        result
+    }
+}
+
+impl<K: Ring> Vector<K> {
+    pub fn sub(&mut self, b: &Vector<K>) {
+        let size = self.size();
+        if size != b.size() {
+            // return Err(format!("Invalid sizes {:?} and {:?}", size, b.size()));
+            return;
+        }
+
+        for column in 0..size {
+            self[column] = self[column].clone() - b[column].clone()
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use matrix::bigint::BigInt;
+
+// Deterministic pseudo-random values, spread over the whole i64 range
+fn values() -> Vec<i64> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut values = vec![0, 1, -1, i64::MAX, i64::MIN + 1, 1 << 32, -(1 << 32)];
+    for _ in 0..40 {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        values.push((state >> (state % 48)) as i64 * if state.is_multiple_of(3) { -1 } else { 1 });
+    }
+    values
+}
+
+#[test]
+fn bigint_arithmetic_matches_i128() {
+    for a in values() {
+        for b in values() {
+            let [x, y] = [BigInt::from(a), BigInt::from(b)];
+            let [a, b] = [a as i128, b as i128];
+            assert_eq!((&x + &y).to_i128(), Some(a + b));
+            assert_eq!((&x - &y).to_i128(), Some(a - b));
+            assert_eq!((&x * &y).to_i128(), Some(a * b));
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            if b != 0 {
+                let (quotient, remainder) = x.div_rem(&y);
+                assert_eq!(quotient.to_i128(), Some(a / b));
+                assert_eq!(remainder.to_i128(), Some(a % b));
+                assert_eq!(x.div_euclid(&y).to_i128(), Some(a.div_euclid(b)));
+                assert_eq!(x.rem_euclid(&y).to_i128(), Some(a.rem_euclid(b)));
+            }
+        }
+    }
+}
+
+#[test]
+fn bigint_long_division() {
+    let a: BigInt = "123456789012345678901234567890123456789012345678901234567890"
+        .parse()
+        .unwrap();
+    let b: BigInt = "-98765432109876543210987654321".parse().unwrap();
+    let (quotient, remainder) = a.div_rem(&b);
+    assert_eq!(&(&quotient * &b) + &remainder, a);
+    assert!(remainder.abs() < b.abs());
+    assert_eq!(quotient.to_string(), "-1249999988609375000142382812499");
+    let power = BigInt::from(2).pow(200);
+    assert_eq!(
+        &power / &BigInt::from(2).pow(136),
+        BigInt::from(1_i128 << 64)
+    );
+    assert_eq!(
+        (&power - &BigInt::from(1)).div_rem(&BigInt::from(2).pow(100)),
+        (
+            &BigInt::from(2).pow(100) - &BigInt::from(1),
+            &BigInt::from(2).pow(100) - &BigInt::from(1)
+        )
+    );
+}
+
+#[test]
+fn bigint_parse_and_display() {
+    let text = "-340282366920938463463374607431768211456000000001";
+    let value: BigInt = text.parse().unwrap();
+    assert_eq!(value.to_string(), text);
+    assert_eq!(value.to_i128(), None);
+    assert_eq!("+42".parse::<BigInt>().unwrap(), BigInt::from(42));
+    assert_eq!("-0".parse::<BigInt>().unwrap().to_string(), "0");
+    assert!("12a".parse::<BigInt>().is_err());
+    assert!("".parse::<BigInt>().is_err());
+    assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+}
+
+#[test]
+fn bigint_gcd() {
+    let [a, b] = [BigInt::from(240), BigInt::from(-46)];
+    assert_eq!(a.gcd(&b), BigInt::from(2));
+    let (gcd, x, y) = a.extended_gcd(&b);
+    assert_eq!(gcd, BigInt::from(2));
+    assert_eq!(&(&a * &x) + &(&b * &y), gcd);
+}
+
+#[test]
+fn bigint_from_f64() {
+    assert_eq!(BigInt::try_from(-12.).unwrap(), BigInt::from(-12));
+    assert_eq!(
+        BigInt::try_from(1e40).unwrap().to_string(),
+        "10000000000000000303786028427003666890752"
+    );
+    assert_eq!(
+        BigInt::try_from(2f64.powi(80)).unwrap(),
+        BigInt::from(2).pow(80)
+    );
+    assert!(BigInt::try_from(0.5).is_err());
+    assert!(BigInt::try_from(f64::INFINITY).is_err());
+}
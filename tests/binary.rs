@@ -0,0 +1,130 @@
+use matrix::io::{
+    binary::{ArchiveReader, ArchiveWriter, BinaryOptions, ScalarType},
+    Endian,
+};
+use matrix::matrix::Matrix;
+use matrix::vector::Vector;
+
+#[test]
+fn binary_matrix_round_trip() {
+    let matrix = Matrix::from([[1., -2.5, 3.], [4., 5., 1. / 3.]]);
+    for endian in [Endian::Little, Endian::Big] {
+        for checksum in [false, true] {
+            let options = BinaryOptions {
+                scalar: ScalarType::F64,
+                endian,
+                checksum,
+            };
+            let mut buffer = vec![];
+            matrix.write_to(&mut buffer, &options).unwrap();
+            assert_eq!(
+                buffer.len(),
+                4 + 4 + 16 + 6 * 8 + if checksum { 4 } else { 0 }
+            );
+            let read = Matrix::read_from(buffer.as_slice()).unwrap();
+            assert_eq!(read.all(), matrix.all());
+        }
+    }
+}
+
+#[test]
+fn binary_f32() {
+    let vector = Vector::from([1.5, -2., 0.1]);
+    let options = BinaryOptions {
+        scalar: ScalarType::F32,
+        ..Default::default()
+    };
+    let mut buffer = vec![];
+    vector.write_to(&mut buffer, &options).unwrap();
+    assert_eq!(buffer.len(), 4 + 4 + 16 + 3 * 4 + 4);
+    let read = Vector::read_from(buffer.as_slice()).unwrap();
+    assert_eq!(read.all(), &vec![1.5, -2., 0.1_f32 as f64]);
+}
+
+#[test]
+fn binary_checksum_mismatch() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    let mut buffer = vec![];
+    matrix
+        .write_to(&mut buffer, &BinaryOptions::default())
+        .unwrap();
+    buffer[30] ^= 0xFF;
+    let error = Matrix::read_from(buffer.as_slice()).unwrap_err();
+    assert!(error.to_string().starts_with("Checksum mismatch"));
+}
+
+#[test]
+fn binary_invalid() {
+    assert!(Matrix::read_from(b"not a record".as_slice()).is_err());
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    let mut buffer = vec![];
+    matrix
+        .write_to(&mut buffer, &BinaryOptions::default())
+        .unwrap();
+    assert!(Vector::read_from(buffer.as_slice()).is_err());
+    buffer[4] = 42;
+    let error = Matrix::read_from(buffer.as_slice()).unwrap_err();
+    assert_eq!(error.to_string(), "Unsupported binary format version 42");
+}
+
+#[test]
+fn binary_invalid_shape() {
+    // No columns, but too many rows to allocate
+    let mut buffer = vec![];
+    Matrix::new([0, 0])
+        .write_to(&mut buffer, &BinaryOptions::default())
+        .unwrap();
+    buffer[8..16].copy_from_slice(&(1_u64 << 60).to_le_bytes());
+    let error = Matrix::read_from(buffer.as_slice()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Matrix of shape [1152921504606846976, 0] is too large, at most 268435456 elements are supported"
+    );
+}
+
+#[test]
+fn binary_archive() {
+    let a = Matrix::from([[1., 2.], [3., 4.]]);
+    let b = Matrix::from([[5., 6., 7.]]);
+    let mut archive = ArchiveWriter::new(vec![], &BinaryOptions::default()).unwrap();
+    archive.write("a", &a).unwrap();
+    archive.write("second", &b).unwrap();
+    let buffer = archive.finish().unwrap();
+
+    let entries: Vec<(String, Matrix)> = ArchiveReader::new(buffer.as_slice())
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].0, "a");
+    assert_eq!(entries[0].1.all(), a.all());
+    assert_eq!(entries[1].0, "second");
+    assert_eq!(entries[1].1.all(), b.all());
+}
+
+#[test]
+fn binary_archive_truncated() {
+    let mut archive = ArchiveWriter::new(vec![], &BinaryOptions::default()).unwrap();
+    archive.write("a", &Matrix::from([[1.]])).unwrap();
+    let buffer = archive.finish().unwrap();
+    let truncated = &buffer[..buffer.len() - 1];
+    let entries: Vec<_> = ArchiveReader::new(truncated).unwrap().collect();
+    assert_eq!(entries.len(), 2);
+    assert!(entries[1].is_err());
+}
+
+#[test]
+fn binary_archive_invalid_name_length() {
+    let mut archive = ArchiveWriter::new(vec![], &BinaryOptions::default()).unwrap();
+    archive.write("a", &Matrix::from([[1.]])).unwrap();
+    let mut buffer = archive.finish().unwrap();
+    // Replace the length of the name by u32::MAX
+    let entry = buffer
+        .windows(6)
+        .position(|window| window == b"\x01\x01\x00\x00\x00a")
+        .unwrap();
+    buffer[entry + 1..entry + 5].copy_from_slice(&u32::MAX.to_le_bytes());
+    let entries: Vec<_> = ArchiveReader::new(buffer.as_slice()).unwrap().collect();
+    assert_eq!(entries.len(), 1);
+    assert!(entries[0].is_err());
+}
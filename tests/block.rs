@@ -0,0 +1,108 @@
+use matrix::{matrix::Matrix, rational::Rational, vector::Vector};
+
+#[test]
+fn matrix_kronecker() {
+    let a = Matrix::from([[1., 2.], [3., 4.]]);
+    let b = Matrix::from([[0., 5.], [6., 7.]]);
+    assert_eq!(
+        a.kronecker(&b).all(),
+        &vec![
+            vec![0., 5., 0., 10.],
+            vec![6., 7., 12., 14.],
+            vec![0., 15., 0., 20.],
+            vec![18., 21., 24., 28.],
+        ]
+    );
+
+    let row = Matrix::from([[1., -1.]]);
+    assert_eq!(
+        Matrix::identity(2, 1.).kronecker(&row).all(),
+        &vec![vec![1., -1., 0., 0.], vec![0., 0., 1., -1.]]
+    );
+    assert_eq!(a.kronecker(&Matrix::new([0, 0])).shape(), [0, 0]);
+}
+
+#[test]
+fn matrix_kronecker_mixed_product() {
+    // (A ⊗ B)(C ⊗ D) = AC ⊗ BD
+    let a = Matrix::from([[1, 2], [3, 4]].map(|row| row.map(Rational::from)));
+    let b = Matrix::from([[0, 1, 2]].map(|row| row.map(Rational::from)));
+    let c = Matrix::from([[2, 0], [1, 1]].map(|row| row.map(Rational::from)));
+    let d = Matrix::from([[1], [-1], [3]].map(|row| row.map(Rational::from)));
+    assert_eq!(
+        a.kronecker(&b).mul_mat(&c.kronecker(&d)).all(),
+        a.mul_mat(&c).kronecker(&b.mul_mat(&d)).all()
+    );
+}
+
+#[test]
+fn matrix_hadamard() {
+    let a = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    let b = Matrix::from([[2., 0., -1.], [0.5, 1., 2.]]);
+    assert_eq!(
+        a.hadamard(&b).all(),
+        &vec![vec![2., 0., -3.], vec![2., 5., 12.]]
+    );
+    assert_eq!(a.hadamard(&a.transpose()).shape(), [0, 0]);
+}
+
+#[test]
+fn vector_outer() {
+    let u = Vector::from([1., 2., 3.]);
+    let v = Vector::from([4., 5.]);
+    assert_eq!(
+        u.outer(&v).all(),
+        &vec![vec![4., 5.], vec![8., 10.], vec![12., 15.]]
+    );
+    assert_eq!(u.outer(&Vector::new(0)).shape(), [3, 0]);
+}
+
+#[test]
+fn matrix_stack() {
+    let a = Matrix::from([[1., 2.], [3., 4.]]);
+    let b = Matrix::from([[5.], [6.]]);
+    let c = Matrix::from([[7., 8.]]);
+    assert_eq!(
+        Matrix::hstack(&[&a, &b]).unwrap().all(),
+        &vec![vec![1., 2., 5.], vec![3., 4., 6.]]
+    );
+    assert_eq!(
+        Matrix::vstack(&[&a, &c]).unwrap().all(),
+        &vec![vec![1., 2.], vec![3., 4.], vec![7., 8.]]
+    );
+    assert!(Matrix::hstack(&[&a, &c]).is_err());
+    assert!(Matrix::vstack(&[&a, &b]).is_err());
+    assert_eq!(Matrix::<f64>::hstack(&[]).unwrap().shape(), [0, 0]);
+}
+
+#[test]
+fn matrix_block() {
+    // Saddle point system [[A, B^T], [B, 0]]
+    let a = Matrix::from([[2., 1.], [1., 3.]]);
+    let b = Matrix::from([[1., -1.]]);
+    let bt = b.transpose();
+    let zero = Matrix::new([1, 1]);
+    assert_eq!(
+        Matrix::block(&[&[&a, &bt], &[&b, &zero]]).unwrap().all(),
+        &vec![vec![2., 1., 1.], vec![1., 3., -1.], vec![1., -1., 0.]]
+    );
+    // Second row of blocks is too narrow
+    assert!(Matrix::block(&[&[&a, &bt], &[&b]]).is_err());
+    // Blocks of the first row have different heights
+    assert!(Matrix::block(&[&[&a, &b]]).is_err());
+}
+
+#[test]
+fn matrix_block_diag() {
+    let a = Matrix::from([[1., 2.], [3., 4.]]);
+    let b = Matrix::from([[5., 6., 7.]]);
+    assert_eq!(
+        Matrix::block_diag(&[&a, &b]).all(),
+        &vec![
+            vec![1., 2., 0., 0., 0.],
+            vec![3., 4., 0., 0., 0.],
+            vec![0., 0., 5., 6., 7.],
+        ]
+    );
+    assert_eq!(Matrix::<f64>::block_diag(&[]).shape(), [0, 0]);
+}
@@ -0,0 +1,125 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn matrix(arguments: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_matrix"))
+        .args(arguments)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn cli_determinant() {
+    let output = matrix(&["det"], "[1 2; 3 4]");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "-2\n");
+}
+
+#[test]
+fn cli_inverse_output_format() {
+    let output = matrix(&["inv", "-o", "matlab", "--fractions"], "1,2\n3,4\n");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "[-2 1; 3/2 -1/2]\n");
+}
+
+#[test]
+fn cli_inverse_singular() {
+    let output = matrix(&["inv"], "[1 2; 2 4]");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "matrix: Singular matrix\n"
+    );
+}
+
+#[test]
+fn cli_inverse_single() {
+    let output = matrix(&["inv"], "[2]");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "[ 0.5 ]\n");
+
+    let output = matrix(&["inv"], "[0]");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "matrix: Singular matrix\n"
+    );
+}
+
+#[test]
+fn cli_transpose_market_input() {
+    let input = "%%MatrixMarket matrix coordinate real general\n2 3 2\n1 2 5\n2 3 7\n";
+    let output = matrix(&["transpose", "-i", "mtx", "-o", "csv"], input);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "0,0\n5,0\n0,7\n");
+}
+
+#[test]
+fn cli_projection() {
+    let output = matrix(
+        &["projection", "90", "1", "1", "50", "-o", "numpy", "-p", "1"],
+        "",
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "np.array([[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, -1.0, -1.0], [0.0, 0.0, -1.0, 0.0]], dtype=float)\n"
+    );
+}
+
+#[test]
+fn cli_errors() {
+    assert_eq!(matrix(&["unknown"], "").status.code(), Some(2));
+    assert_eq!(matrix(&["det", "-o", "pdf"], "").status.code(), Some(2));
+    assert_eq!(matrix(&["mul"], "").status.code(), Some(2));
+    assert_eq!(matrix(&["det"], "[1 2; 3]").status.code(), Some(3));
+    assert_eq!(matrix(&["det"], "[1 2 3]").status.code(), Some(1));
+}
+
+#[test]
+fn cli_repl_script() {
+    let script = "A = [1 2; 3 4];\nb = [5 6]\nx = solve(A, b);\nA * x\ndet(A')\nlerp(1, 3, 0.5)\n";
+    let output = matrix(&["repl"], script);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "b =\n[ 5  6 ]\nans =\n[ 5  6 ]\nans = -2\nans = 2\n"
+    );
+}
+
+#[test]
+fn cli_repl_size_and_scalar_inverse() {
+    let output = matrix(&["repl"], "identity(1e12)\ninv(2)\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "ans =\n[ 0.5 ]\n");
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: identity expects a size of at most 16384, got 1000000000000\n"
+    );
+}
+
+#[test]
+fn cli_repl_errors() {
+    let output = matrix(&["repl"], "inv([1 2; 2 4])\ny = x + 1\n[1 2] + [1 2 3]\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: Singular matrix\nerror: Unknown variable 'x'\nerror: Invalid shapes for addition: vector [1, 2] and vector [1, 3]\n"
+    );
+}
@@ -0,0 +1,90 @@
+use matrix::{matrix::Matrix, rational::Rational, vector::Vector};
+
+#[test]
+fn matrix_map() {
+    let matrix = Matrix::from([[1., -2.], [3., -4.]]);
+    let scale = 10.;
+    assert_eq!(
+        matrix.map(|value| value * scale).all(),
+        &vec![vec![10., -20.], vec![30., -40.]]
+    );
+    // The type of the elements can change
+    assert_eq!(
+        matrix
+            .map(|value| Rational::try_from(*value).unwrap())
+            .all(),
+        Matrix::from([[1, -2], [3, -4]].map(|row| row.map(Rational::from))).all()
+    );
+    assert_eq!(
+        matrix
+            .map_indexed(|row, column, value| value + (10 * row + column) as f64)
+            .all(),
+        &vec![vec![1., -1.], vec![13., 7.]]
+    );
+    assert_eq!(Matrix::new([0, 0]).map(|value| value + 1.).shape(), [0, 0]);
+}
+
+#[test]
+fn matrix_zip_map() {
+    let a = Matrix::from([[1., 2.], [3., 4.]]);
+    let b = Matrix::from([[4., 3.], [2., 1.]]);
+    assert_eq!(
+        a.zip_map(&b, |a, b| a.max(*b)).unwrap().all(),
+        &vec![vec![4., 3.], vec![3., 4.]]
+    );
+    assert!(a.zip_map(&Matrix::new([1, 2]), |a, b| a + b).is_err());
+}
+
+#[test]
+fn matrix_apply_fold() {
+    let mut matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    let mut calls = 0;
+    matrix.apply_mut(|value| {
+        calls += 1;
+        *value = *value * *value
+    });
+    assert_eq!(calls, 4);
+    assert_eq!(matrix.all(), &vec![vec![1., 4.], vec![9., 16.]]);
+
+    assert_eq!(matrix.fold(0., |sum, value| sum + value), 30.);
+    // Row by row
+    let mut order = vec![];
+    matrix.for_each(|value| order.push(*value));
+    assert_eq!(order, vec![1., 4., 9., 16.]);
+}
+
+#[test]
+fn vector_closures() {
+    let vector = Vector::from([1., 2., 3.]);
+    let offset = 0.5;
+    assert_eq!(
+        vector.map(|value| value + offset).all(),
+        &vec![1.5, 2.5, 3.5]
+    );
+    assert_eq!(
+        vector
+            .map_indexed(|index, value| value * index as f64)
+            .all(),
+        &vec![0., 2., 6.]
+    );
+    assert_eq!(
+        vector
+            .zip_map(&Vector::from([3., 2., 1.]), |a, b| a - b)
+            .unwrap()
+            .all(),
+        &vec![-2., 0., 2.]
+    );
+    assert!(vector.zip_map(&Vector::new(2), |a, b| a + b).is_err());
+
+    let mut vector = vector;
+    vector.apply_mut(|value| *value = -*value);
+    assert_eq!(vector.all(), &vec![-1., -2., -3.]);
+    assert_eq!(vector.fold(1., |product, value| product * value), -6.);
+    let mut count = 0;
+    vector.for_each(|value| {
+        if *value < -1. {
+            count += 1
+        }
+    });
+    assert_eq!(count, 2);
+}
@@ -0,0 +1,70 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+#[test]
+fn matrix_try_from_rows() {
+    let matrix = Matrix::try_from_rows(vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+    assert_eq!(matrix.shape(), [2, 2]);
+    assert!(Matrix::try_from_rows(vec![vec![1., 2.], vec![3.]]).is_err());
+    assert_eq!(
+        Matrix::<f64>::try_from_rows(vec![]).unwrap().shape(),
+        [0, 0]
+    );
+}
+
+#[test]
+fn matrix_from_fn() {
+    let hilbert = Matrix::from_fn([2, 3], |row, column| 1. / (row + column + 1) as f64);
+    assert_eq!(
+        hilbert.all(),
+        &vec![vec![1., 0.5, 1. / 3.], vec![0.5, 1. / 3., 0.25]]
+    );
+}
+
+#[test]
+fn matrix_from_diagonal() {
+    assert_eq!(
+        Matrix::from_diagonal(&Vector::from([1., 2., 3.])).all(),
+        &vec![vec![1., 0., 0.], vec![0., 2., 0.], vec![0., 0., 3.]]
+    );
+    assert_eq!(Matrix::from_diagonal(&Vector::new(0)).shape(), [0, 0]);
+}
+
+#[test]
+fn matrix_from_columns() {
+    let columns = [Vector::from([1., 2., 3.]), Vector::from([4., 5., 6.])];
+    assert_eq!(
+        Matrix::from_columns(&columns).unwrap().all(),
+        &vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]]
+    );
+    assert!(Matrix::from_columns(&[Vector::from([1., 2.]), Vector::from([1.])]).is_err());
+    assert_eq!(Matrix::<f64>::from_columns(&[]).unwrap().shape(), [0, 0]);
+}
+
+#[test]
+fn matrix_reshape_flatten() {
+    let matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    assert_eq!(
+        matrix.reshape([3, 2]).unwrap().all(),
+        &vec![vec![1., 2.], vec![3., 4.], vec![5., 6.]]
+    );
+    assert_eq!(
+        matrix.reshape([6, 1]).unwrap().all(),
+        &vec![vec![1.], vec![2.], vec![3.], vec![4.], vec![5.], vec![6.]]
+    );
+    assert!(matrix.reshape([4, 2]).is_err());
+    assert!(matrix.reshape([usize::MAX, 2]).is_err());
+    assert_eq!(matrix.flatten().all(), &vec![1., 2., 3., 4., 5., 6.]);
+    assert_eq!(
+        matrix.flatten().reshape().reshape([2, 3]).unwrap().all(),
+        matrix.all()
+    );
+}
+
+#[test]
+fn vector_to_column_matrix() {
+    let vector = Vector::from([1., 2., 3.]);
+    let column = vector.to_column_matrix();
+    assert_eq!(column.shape(), [3, 1]);
+    assert_eq!(column.all(), &vec![vec![1.], vec![2.], vec![3.]]);
+    assert_eq!(column.transpose().all(), vector.reshape().all());
+}
@@ -42,7 +42,7 @@ fn cosine_example_5() {
     let e2 = Vector::from([4., 5., 6.]);
 
     let cos = angle_cos(&e1, &e2);
-    let expected = 0.974631846;
+    let expected = 0.974_631_85;
     assert_eq!(cos as f32, expected);
 }
 
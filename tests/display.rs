@@ -0,0 +1,59 @@
+use matrix::matrix::Matrix;
+use matrix::vector::Vector;
+
+#[test]
+fn display_vector() {
+    let vector = Vector::from([1., -2.5, 3.]);
+    assert_eq!(format!("{}", vector), "[ 1  -2.5  3 ]");
+}
+
+#[test]
+fn display_vector_compact() {
+    let vector = Vector::from([1., -2.5, 3.]);
+    assert_eq!(format!("{:#.1}", vector), "[1.0, -2.5, 3.0]");
+}
+
+#[test]
+fn display_matrix() {
+    let matrix = Matrix::from([[1., -2.], [30., 4.]]);
+    let expected = "┌        ┐\n│  1  -2 │\n│ 30   4 │\n└        ┘";
+    assert_eq!(format!("{}", matrix), expected);
+}
+
+#[test]
+fn display_matrix_precision_width() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    let expected = "┌                ┐\n│  1.000   2.000 │\n│  3.000   4.000 │\n└                ┘";
+    assert_eq!(format!("{:6.3}", matrix), expected);
+}
+
+#[test]
+fn display_matrix_compact() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    assert_eq!(format!("{:#}", matrix), "[1, 2; 3, 4]");
+}
+
+#[test]
+fn display_matrix_truncated() {
+    let matrix = Matrix::identity(12, 1.);
+    let display = format!("{}", matrix);
+    let lines: Vec<&str> = display.lines().collect();
+    // 4 rows, the ellipsis row and 4 rows, surrounded by the brackets
+    assert_eq!(lines.len(), 11);
+    assert_eq!(lines[1], "│ 1  0  0  0  …  0  0  0  0 │");
+    assert_eq!(lines[5], "│ ⋮  ⋮  ⋮  ⋮  ⋱  ⋮  ⋮  ⋮  ⋮ │");
+}
+
+#[test]
+fn display_empty() {
+    assert_eq!(format!("{}", Matrix::new([0, 0])), "[]");
+    assert_eq!(format!("{}", Vector::new(0)), "[]");
+}
+
+#[test]
+fn display_matrix_ragged() {
+    let matrix = Matrix::from(vec![vec![1., 2.], vec![3.]]);
+    assert_eq!(format!("{}", matrix), "[1, 2; 3]");
+    let matrix = Matrix::from(vec![vec![], vec![3.]]);
+    assert_eq!(format!("{:.1}", matrix), "[; 3.0]");
+}
@@ -0,0 +1,82 @@
+use matrix::bigint::BigInt;
+use matrix::export::{ExportOptions, Latex};
+use matrix::matrix::Matrix;
+use matrix::modular::Zp;
+use matrix::rational::Rational;
+use matrix::vector::Vector;
+
+#[test]
+fn export_latex() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    let expected = "\\begin{bmatrix}\n  1 & 2 \\\\\n  3 & 4\n\\end{bmatrix}";
+    assert_eq!(matrix.to_latex(&ExportOptions::default()), expected);
+}
+
+#[test]
+fn export_latex_fractions() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]).inverse().unwrap();
+    let options = ExportOptions {
+        fractions: true,
+        ..Default::default()
+    };
+    let expected =
+        "\\begin{bmatrix}\n  -2 & 1 \\\\\n  \\frac{3}{2} & -\\frac{1}{2}\n\\end{bmatrix}";
+    assert_eq!(matrix.to_latex(&options), expected);
+}
+
+#[test]
+fn export_latex_exact_scalars() {
+    let options = ExportOptions::default();
+    assert_eq!(Rational::new(-3, 4).to_latex(&options), "-\\frac{3}{4}");
+    assert_eq!(Rational::from(5).to_latex(&options), "5");
+    let large: BigInt = "123456789012345678901234567890".parse().unwrap();
+    assert_eq!(large.to_latex(&options), "123456789012345678901234567890");
+    assert_eq!(Zp::<7>::from(-1).to_latex(&options), "6");
+}
+
+#[test]
+fn export_markdown() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    let options = ExportOptions {
+        precision: Some(1),
+        ..Default::default()
+    };
+    let expected = "| 0 | 1 |\n| ---: | ---: |\n| 1.0 | 2.0 |\n| 3.0 | 4.0 |";
+    assert_eq!(matrix.to_markdown(&options), expected);
+}
+
+#[test]
+fn export_matlab() {
+    let matrix = Matrix::from([[1., 2.5], [-3., 4.]]);
+    assert_eq!(matrix.to_matlab(&ExportOptions::default()), "[1 2.5; -3 4]");
+    let vector = Vector::from([1., f64::INFINITY, f64::NAN]);
+    assert_eq!(vector.to_matlab(&ExportOptions::default()), "[1 Inf NaN]");
+}
+
+#[test]
+fn export_numpy() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    assert_eq!(
+        matrix.to_numpy(&ExportOptions::default()),
+        "np.array([[1, 2], [3, 4]], dtype=float)"
+    );
+    let vector = Vector::from([1. / 3., 0.25]);
+    let options = ExportOptions {
+        fractions: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        vector.to_numpy(&options),
+        "np.array([1/3, 1/4], dtype=float)"
+    );
+}
+
+#[test]
+fn export_fractions_inexact() {
+    let vector = Vector::from([std::f64::consts::PI]);
+    let options = ExportOptions {
+        precision: Some(3),
+        fractions: true,
+    };
+    assert_eq!(vector.to_matlab(&options), "[3.142]");
+}
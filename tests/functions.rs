@@ -0,0 +1,172 @@
+use matrix::matrix::Matrix;
+
+fn assert_close(result: &Matrix, expected: &Matrix, tolerance: f64) {
+    assert_eq!(result.shape(), expected.shape());
+    for (a, b) in result
+        .iter_rows()
+        .flatten()
+        .zip(expected.iter_rows().flatten())
+    {
+        assert!(
+            (a - b).abs() <= tolerance * (1. + b.abs()),
+            "{} is not close to {}\n{}\n{}",
+            a,
+            b,
+            result,
+            expected
+        );
+    }
+}
+
+#[test]
+fn matrix_exp() {
+    let zero = Matrix::new([3, 3]);
+    assert_close(&zero.exp().unwrap(), &Matrix::identity(3, 1.), 1e-15);
+
+    let diagonal = Matrix::from([[1., 0.], [0., -2.]]);
+    let expected = Matrix::from([[1_f64.exp(), 0.], [0., (-2_f64).exp()]]);
+    assert_close(&diagonal.exp().unwrap(), &expected, 1e-14);
+
+    // exp(At) of a harmonic oscillator is a rotation
+    let t = 10.;
+    let oscillator = Matrix::from([[0., t], [-t, 0.]]);
+    let rotation = Matrix::from([[t.cos(), t.sin()], [-t.sin(), t.cos()]]);
+    assert_close(&oscillator.exp().unwrap(), &rotation, 1e-12);
+
+    // Nilpotent matrix, exp(N) = I + N + N^2 / 2
+    let nilpotent = Matrix::from([[0., 1., 2.], [0., 0., 3.], [0., 0., 0.]]);
+    let expected = Matrix::from([[1., 1., 3.5], [0., 1., 3.], [0., 0., 1.]]);
+    assert_close(&nilpotent.exp().unwrap(), &expected, 1e-14);
+
+    let single = Matrix::from([[1.]]);
+    assert_close(
+        &single.exp().unwrap(),
+        &Matrix::from([[1_f64.exp()]]),
+        1e-14,
+    );
+
+    assert!(Matrix::from([[1., 2.]]).exp().is_err());
+    assert!(Matrix::from([[f64::INFINITY, 0.], [0., 1.]]).exp().is_err());
+    assert!(Matrix::from([[f64::NAN]]).exp().is_err());
+}
+
+#[test]
+fn matrix_exp_inverse() {
+    let matrix = Matrix::from([[1., 2., -1.], [0.5, -3., 4.], [2., 1., 0.]]);
+    let product = matrix
+        .exp()
+        .unwrap()
+        .mul_mat(&(matrix.clone() * -1.).exp().unwrap());
+    assert_close(&product, &Matrix::identity(3, 1.), 1e-12);
+}
+
+#[test]
+fn matrix_sqrt() {
+    let matrix = Matrix::from([[4., 0.], [0., 9.]]);
+    assert_close(
+        &matrix.sqrt().unwrap(),
+        &Matrix::from([[2., 0.], [0., 3.]]),
+        1e-14,
+    );
+
+    let matrix = Matrix::from([[33., 24.], [48., 57.]]);
+    let root = matrix.sqrt().unwrap();
+    assert_close(&root, &Matrix::from([[5., 2.], [4., 7.]]), 1e-13);
+
+    assert_close(
+        &Matrix::from([[4.]]).sqrt().unwrap(),
+        &Matrix::from([[2.]]),
+        1e-14,
+    );
+
+    // No real square root
+    assert!(Matrix::from([[-1., 0.], [0., -1.]]).sqrt().is_err());
+    assert!(Matrix::from([[1., 1.], [1., 1.]]).sqrt().is_err());
+}
+
+#[test]
+fn matrix_log() {
+    let matrix = Matrix::from([[1., 2., 0.], [0.5, 3., 1.], [0., 1., 2.]]);
+    assert_close(&matrix.exp().unwrap().log().unwrap(), &matrix, 1e-10);
+
+    let diagonal = Matrix::from([[std::f64::consts::E, 0.], [0., 100.]]);
+    let expected = Matrix::from([[1., 0.], [0., 100_f64.ln()]]);
+    assert_close(&diagonal.log().unwrap(), &expected, 1e-12);
+
+    assert_close(
+        &Matrix::identity(2, 1.).log().unwrap(),
+        &Matrix::new([2, 2]),
+        1e-15,
+    );
+    assert_close(
+        &Matrix::from([[std::f64::consts::E]]).log().unwrap(),
+        &Matrix::from([[1.]]),
+        1e-14,
+    );
+    assert!(Matrix::from([[-1., 0.], [0., 2.]]).log().is_err());
+}
+
+#[test]
+fn matrix_pow() {
+    let matrix = Matrix::from([[1., 1.], [1., 0.]]);
+    // Fibonacci numbers
+    assert_eq!(
+        matrix.pow(10).unwrap().all(),
+        &vec![vec![89., 55.], vec![55., 34.]]
+    );
+    assert_eq!(matrix.pow(0).unwrap().all(), Matrix::identity(2, 1.).all());
+    assert_eq!(
+        matrix.pow(-1).unwrap().all(),
+        &vec![vec![0., 1.], vec![1., -1.]]
+    );
+    assert_eq!(
+        matrix.pow(-3).unwrap().all(),
+        &vec![vec![-1., 2.], vec![2., -3.]]
+    );
+    assert!(Matrix::from([[1., 2.], [2., 4.]]).pow(-1).is_err());
+    assert_eq!(
+        Matrix::from([[4.]]).pow(-1).unwrap().all(),
+        &vec![vec![0.25]]
+    );
+    assert!(Matrix::from([[0.]]).pow(-1).is_err());
+    assert!(Matrix::from([[1., 2.]]).pow(2).is_err());
+}
+
+#[test]
+fn matrix_powf() {
+    let matrix = Matrix::from([[4., 1.], [1., 3.]]);
+    assert_close(&matrix.powf(0.5).unwrap(), &matrix.sqrt().unwrap(), 1e-12);
+    assert_close(&matrix.powf(2.).unwrap(), &matrix.pow(2).unwrap(), 1e-12);
+    assert_close(
+        &matrix.powf(-1.).unwrap(),
+        &matrix.inverse().unwrap(),
+        1e-12,
+    );
+    // Symmetric but not positive definite
+    assert!(Matrix::from([[1., 2.], [2., 1.]]).powf(0.5).is_err());
+    assert!(Matrix::from([[1., 2.], [0., 1.]]).powf(0.5).is_err());
+}
+
+#[test]
+fn matrix_symmetric_eigen() {
+    let matrix = Matrix::from([[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]);
+    let (values, vectors) = matrix.symmetric_eigen().unwrap();
+    let sqrt2 = 2_f64.sqrt();
+    let expected = [2. + sqrt2, 2., 2. - sqrt2];
+    for (value, expected) in values.iter().zip(expected.iter()) {
+        assert!((value - expected).abs() < 1e-12);
+    }
+    // A V = V diag(λ)
+    let mut scaled = vectors.clone();
+    for row in 0..3 {
+        for column in 0..3 {
+            scaled[row][column] *= values[column];
+        }
+    }
+    assert_close(&matrix.mul_mat(&vectors), &scaled, 1e-12);
+    assert_close(
+        &vectors.transpose().mul_mat(&vectors),
+        &Matrix::identity(3, 1.),
+        1e-12,
+    );
+}
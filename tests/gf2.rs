@@ -0,0 +1,69 @@
+use matrix::{gf2::BitMatrix, modular::Zp};
+
+// Parity-check matrix of the Hamming (7, 4) code
+fn hamming_parity() -> BitMatrix {
+    BitMatrix::from([
+        [1, 0, 1, 0, 1, 0, 1],
+        [0, 1, 1, 0, 0, 1, 1],
+        [0, 0, 0, 1, 1, 1, 1],
+    ])
+}
+
+#[test]
+fn bit_matrix_hamming_code() {
+    let parity = hamming_parity();
+    assert_eq!(parity.rank(), 3);
+    let generator = parity.null_space();
+    assert_eq!(generator.shape(), [4, 7]);
+    assert_eq!(generator.rank(), 4);
+    // Every codeword satisfies all of the parity checks
+    assert_eq!(
+        parity.mul_mat(&generator.transpose()),
+        BitMatrix::new([3, 4])
+    );
+    // The minimum distance of the code is 3
+    assert!((0..4).all(|row| generator.row_weight(row) >= 3));
+}
+
+#[test]
+fn bit_matrix_inverse() {
+    let matrix = BitMatrix::from([[1, 1, 0], [0, 1, 1], [0, 0, 1]]);
+    let inverse = matrix.inverse().unwrap();
+    assert_eq!(inverse, BitMatrix::from([[1, 1, 1], [0, 1, 1], [0, 0, 1]]));
+    assert_eq!(matrix.mul_mat(&inverse), BitMatrix::identity(3));
+    assert!(matrix.determinant());
+
+    // The rows sum to zero over GF(2)
+    let singular = BitMatrix::from([[1, 1, 0], [0, 1, 1], [1, 0, 1]]);
+    assert!(!singular.determinant());
+    assert_eq!(singular.inverse(), Err("Singular matrix".to_string()));
+    assert_eq!(
+        singular.row_echelon(),
+        BitMatrix::from([[1, 0, 1], [0, 1, 1], [0, 0, 0]])
+    );
+}
+
+#[test]
+fn bit_matrix_wide_rows() {
+    // Rows spanning multiple words
+    let size = 130;
+    let mut matrix = BitMatrix::identity(size);
+    for column in 1..size {
+        matrix.set(0, column, true);
+    }
+    matrix.set(size - 1, 3, true);
+    let inverse = matrix.inverse().unwrap();
+    assert_eq!(matrix.mul_mat(&inverse), BitMatrix::identity(size));
+    assert_eq!(matrix.rank(), size);
+    assert_eq!(matrix.row_weight(0), size);
+}
+
+#[test]
+fn bit_matrix_conversions() {
+    let matrix = BitMatrix::from([[1, 0], [1, 1]]);
+    let generic = matrix.to_matrix();
+    assert_eq!(generic[1][0], Zp::<2>::new(1));
+    assert_eq!(BitMatrix::from(&generic), matrix);
+    assert_eq!(generic.determinant(), Zp::new(1));
+    assert_eq!(format!("{:#}", matrix), "[1, 0; 1, 1]");
+}
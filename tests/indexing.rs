@@ -0,0 +1,55 @@
+use matrix::matrix::Matrix;
+
+#[test]
+fn matrix_tuple_index() {
+    let mut matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    assert_eq!(matrix[(0, 2)], 3.);
+    assert_eq!(matrix[(1, 0)], 4.);
+    matrix[(1, 1)] = -5.;
+    matrix[(0, 0)] += 10.;
+    assert_eq!(matrix.all(), &vec![vec![11., 2., 3.], vec![4., -5., 6.]]);
+    // Rows are slices
+    assert_eq!(&matrix[1], &[4., -5., 6.]);
+    matrix[0].copy_from_slice(&[0., 0., 0.]);
+    assert_eq!(matrix[(0, 1)], 0.);
+}
+
+#[test]
+#[should_panic]
+fn matrix_tuple_index_out_of_bounds() {
+    let matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    let _ = matrix[(2, 0)];
+}
+
+#[test]
+fn matrix_get() {
+    let mut matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    assert_eq!(matrix.get(1, 0), Some(&3.));
+    assert_eq!(matrix.get(2, 0), None);
+    assert_eq!(matrix.get(0, 2), None);
+    if let Some(value) = matrix.get_mut(0, 1) {
+        *value = 20.;
+    }
+    assert_eq!(matrix[(0, 1)], 20.);
+    assert_eq!(matrix.get_mut(5, 5), None);
+}
+
+#[test]
+fn matrix_slice() {
+    let matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+    assert_eq!(
+        matrix.slice(1..3, ..).unwrap().all(),
+        &vec![vec![4., 5., 6.], vec![7., 8., 9.]]
+    );
+    assert_eq!(
+        matrix.slice(..=1, 1..).unwrap().all(),
+        &vec![vec![2., 3.], vec![5., 6.]]
+    );
+    assert_eq!(matrix.slice(.., ..).unwrap().all(), matrix.all());
+    assert_eq!(matrix.slice(2..2, ..).unwrap().shape(), [0, 0]);
+    assert!(matrix.slice(1..4, ..).is_err());
+    assert!(matrix.slice(.., 2..=3).is_err());
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = matrix.slice(2..1, ..);
+    assert!(reversed.is_err());
+}
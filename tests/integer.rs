@@ -0,0 +1,89 @@
+use matrix::{bigint::BigInt, matrix::Matrix};
+
+fn integer_matrix<const N: usize, const M: usize>(rows: [[i64; N]; M]) -> Matrix<BigInt> {
+    Matrix::from(
+        rows.iter()
+            .map(|row| row.iter().map(|value| BigInt::from(*value)).collect())
+            .collect::<Vec<Vec<BigInt>>>(),
+    )
+}
+
+fn is_unimodular(matrix: &Matrix<BigInt>) -> bool {
+    matrix.determinant().abs() == BigInt::from(1)
+}
+
+#[test]
+fn integer_determinant_is_exact() {
+    // f64 can't represent the determinant, 2^53 + 1
+    let matrix = integer_matrix([[1 << 27, 1], [-1, 1 << 26]]);
+    assert_eq!(matrix.determinant(), BigInt::from((1_i64 << 53) + 1));
+
+    let large = Matrix::from(vec![
+        vec!["1000000000000000000000".parse().unwrap(), BigInt::from(7)],
+        vec![BigInt::from(3), "-999999999999999999999".parse().unwrap()],
+    ]);
+    assert_eq!(
+        large.determinant().to_string(),
+        "-999999999999999999999000000000000000000021"
+    );
+
+    let singular = integer_matrix([[0, 2, 4], [0, 1, 2], [3, 5, 7]]);
+    assert_eq!(singular.determinant(), BigInt::from(0));
+    let matrix = integer_matrix([[2, -3, 1], [2, 0, -1], [1, 4, 5]]);
+    assert_eq!(matrix.determinant(), BigInt::from(49));
+}
+
+#[test]
+fn integer_hermite_normal_form() {
+    let matrix = integer_matrix([[2, 3, 6, 2], [5, 6, 1, 6], [8, 3, 1, 1]]);
+    let (hermite, transform) = matrix.hermite_normal_form();
+    let expected = integer_matrix([[1, 0, 50, -11], [0, 3, 28, -2], [0, 0, 61, -13]]);
+    assert_eq!(hermite.all(), expected.all());
+    assert_eq!(transform.mul_mat(&matrix).all(), hermite.all());
+    assert!(is_unimodular(&transform));
+}
+
+#[test]
+fn integer_hermite_normal_form_rank_deficient() {
+    let matrix = integer_matrix([[3, 3, 1, 4], [0, 1, 0, 0], [0, 0, 19, 16], [0, 0, 0, 3]]);
+    let (hermite, transform) = matrix.hermite_normal_form();
+    let expected = integer_matrix([[3, 0, 1, 1], [0, 1, 0, 0], [0, 0, 19, 1], [0, 0, 0, 3]]);
+    assert_eq!(hermite.all(), expected.all());
+    assert_eq!(transform.mul_mat(&matrix).all(), hermite.all());
+
+    let matrix = integer_matrix([[2, 4], [-1, -2], [3, 6]]);
+    let (hermite, transform) = matrix.hermite_normal_form();
+    assert_eq!(
+        hermite.all(),
+        integer_matrix([[1, 2], [0, 0], [0, 0]]).all()
+    );
+    assert!(is_unimodular(&transform));
+}
+
+#[test]
+fn integer_smith_normal_form() {
+    let matrix = integer_matrix([[2, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+    let (smith, left, right) = matrix.smith_normal_form();
+    assert_eq!(
+        smith.all(),
+        integer_matrix([[2, 0, 0], [0, 6, 0], [0, 0, 12]]).all()
+    );
+    assert_eq!(left.mul_mat(&matrix).mul_mat(&right).all(), smith.all());
+    assert!(is_unimodular(&left));
+    assert!(is_unimodular(&right));
+}
+
+#[test]
+fn integer_smith_normal_form_divisibility() {
+    // diag(2, 3) must become diag(1, 6)
+    let matrix = integer_matrix([[2, 0], [0, 3]]);
+    let (smith, left, right) = matrix.smith_normal_form();
+    assert_eq!(smith.all(), integer_matrix([[1, 0], [0, 6]]).all());
+    assert_eq!(left.mul_mat(&matrix).mul_mat(&right).all(), smith.all());
+
+    let matrix = integer_matrix([[6, 4, 0], [0, 0, 0]]);
+    let (smith, left, right) = matrix.smith_normal_form();
+    assert_eq!(smith.all(), integer_matrix([[2, 0, 0], [0, 0, 0]]).all());
+    assert_eq!(left.mul_mat(&matrix).mul_mat(&right).all(), smith.all());
+    assert!(is_unimodular(&right));
+}
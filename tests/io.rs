@@ -0,0 +1,168 @@
+use matrix::io::{
+    self,
+    csv::{read_csv, read_csv_with_header, write_csv, write_csv_with_header, CsvOptions},
+    market::{
+        read_matrix_market, write_matrix_market, MarketField, MarketFormat, MarketOptions,
+        MarketSymmetry,
+    },
+};
+use matrix::matrix::Matrix;
+
+#[test]
+fn market_read_coordinate() {
+    let file = "%%MatrixMarket matrix coordinate real general\n% comment\n2 3 3\n1 1 1.5\n2 3 -2\n1 2 4e1\n";
+    let matrix = read_matrix_market(file.as_bytes()).unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1.5, 40., 0.], vec![0., 0., -2.]]);
+}
+
+#[test]
+fn market_read_coordinate_pattern_symmetric() {
+    let file = "%%MatrixMarket matrix coordinate pattern symmetric\n3 3 2\n2 1\n3 3\n";
+    let matrix = read_matrix_market(file.as_bytes()).unwrap();
+    assert_eq!(
+        matrix.all(),
+        &vec![vec![0., 1., 0.], vec![1., 0., 0.], vec![0., 0., 1.]]
+    );
+}
+
+#[test]
+fn market_read_array() {
+    let file = "%%MatrixMarket matrix array integer general\n2 2\n1\n3\n2\n4\n";
+    let matrix = read_matrix_market(file.as_bytes()).unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2.], vec![3., 4.]]);
+}
+
+#[test]
+fn market_read_array_symmetric() {
+    let file = "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n";
+    let matrix = read_matrix_market(file.as_bytes()).unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2.], vec![2., 3.]]);
+}
+
+#[test]
+fn market_read_invalid() {
+    let file = "%%MatrixMarket matrix coordinate complex general\n1 1 0\n";
+    assert!(read_matrix_market(file.as_bytes()).is_err());
+    let file = "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n";
+    assert!(read_matrix_market(file.as_bytes()).is_err());
+    let file = "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n";
+    assert!(read_matrix_market(file.as_bytes()).is_err());
+    // No columns, the rows are still allocated
+    let file = "%%MatrixMarket matrix coordinate real general\n1152921504606846976 0 0\n";
+    assert!(read_matrix_market(file.as_bytes()).is_err());
+}
+
+#[test]
+fn market_read_invalid_size() {
+    // Too large to be allocated, or overflowing
+    let file = "%%MatrixMarket matrix coordinate real general\n100000000 100000000 0\n";
+    assert!(read_matrix_market(file.as_bytes()).is_err());
+    let file = format!(
+        "%%MatrixMarket matrix array real general\n{} 2\n",
+        usize::MAX
+    );
+    assert!(read_matrix_market(file.as_bytes()).is_err());
+}
+
+#[test]
+fn market_round_trip() {
+    let matrix = Matrix::from([[4., 1., 0.], [1., 3., 2.], [0., 2., 5.]]);
+    for format in [MarketFormat::Coordinate, MarketFormat::Array] {
+        for field in [MarketField::Real, MarketField::Integer] {
+            for symmetry in [MarketSymmetry::General, MarketSymmetry::Symmetric] {
+                let options = MarketOptions {
+                    format,
+                    field,
+                    symmetry,
+                };
+                let mut buffer = vec![];
+                write_matrix_market(&mut buffer, &matrix, &options).unwrap();
+                let read = read_matrix_market(buffer.as_slice()).unwrap();
+                assert_eq!(read.all(), matrix.all());
+            }
+        }
+    }
+}
+
+#[test]
+fn market_write_invalid() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.5]]);
+    let symmetric = MarketOptions {
+        symmetry: MarketSymmetry::Symmetric,
+        ..Default::default()
+    };
+    assert!(write_matrix_market(vec![], &matrix, &symmetric).is_err());
+    let integer = MarketOptions {
+        field: MarketField::Integer,
+        ..Default::default()
+    };
+    assert!(write_matrix_market(vec![], &matrix, &integer).is_err());
+
+    // The values would be lost, only zeros and ones are written as a pattern
+    let pattern = MarketOptions {
+        field: MarketField::Pattern,
+        ..Default::default()
+    };
+    assert!(write_matrix_market(vec![], &matrix, &pattern).is_err());
+    let identity = Matrix::identity(2, 1.);
+    let mut file = vec![];
+    write_matrix_market(&mut file, &identity, &pattern).unwrap();
+    assert_eq!(
+        read_matrix_market(file.as_slice()).unwrap().all(),
+        identity.all()
+    );
+}
+
+#[test]
+fn csv_read() {
+    let file = "1,2,3\n4, 5 ,6\n";
+    let matrix = read_csv(file.as_bytes(), &CsvOptions::default()).unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+}
+
+#[test]
+fn csv_read_header_delimiter() {
+    let file = "\"x;y\";z\n1;2\n3;4\n";
+    let options = CsvOptions {
+        delimiter: ';',
+        header: true,
+    };
+    let (header, matrix) = read_csv_with_header(file.as_bytes(), &options).unwrap();
+    assert_eq!(header, vec!["x;y".to_string(), "z".to_string()]);
+    assert_eq!(matrix.all(), &vec![vec![1., 2.], vec![3., 4.]]);
+}
+
+#[test]
+fn csv_read_invalid() {
+    let error = read_csv("1,2\n3,4,5\n".as_bytes(), &CsvOptions::default()).unwrap_err();
+    assert_eq!(error.to_string(), "Line 2 has 3 values, expected 2");
+    let error = read_csv("1,2\n3,a\n".as_bytes(), &CsvOptions::default()).unwrap_err();
+    assert_eq!(error.to_string(), "Invalid value 'a' at line 2, column 2");
+}
+
+#[test]
+fn csv_write() {
+    let matrix = Matrix::from([[1., 2.5], [-3., 4.]]);
+    let mut buffer = vec![];
+    let options = CsvOptions {
+        delimiter: '\t',
+        header: false,
+    };
+    write_csv(&mut buffer, &matrix, &options).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), "1\t2.5\n-3\t4\n");
+
+    let mut buffer = vec![];
+    write_csv_with_header(&mut buffer, &["a", "b,c"], &matrix, &CsvOptions::default()).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "a,\"b,c\"\n1,2.5\n-3,4\n"
+    );
+}
+
+#[test]
+fn io_to_vector() {
+    let column = read_csv("1\n2\n3\n".as_bytes(), &CsvOptions::default()).unwrap();
+    assert_eq!(io::to_vector(&column).unwrap().all(), &vec![1., 2., 3.]);
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    assert!(io::to_vector(&matrix).is_err());
+}
@@ -0,0 +1,148 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+#[test]
+fn matrix_iter() {
+    let mut matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    let iter = matrix.iter();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(
+        iter.copied().collect::<Vec<f64>>(),
+        vec![1., 2., 3., 4., 5., 6.]
+    );
+    assert_eq!(matrix.iter().next_back(), Some(&6.));
+
+    for value in matrix.iter_mut() {
+        *value *= 2.;
+    }
+    for value in &mut matrix {
+        *value += 1.;
+    }
+    assert_eq!(matrix.all(), &vec![vec![3., 5., 7.], vec![9., 11., 13.]]);
+    assert_eq!((&matrix).into_iter().sum::<f64>(), 48.);
+    assert_eq!(matrix.into_iter().len(), 6);
+
+    let mut iter = Matrix::from([[1., 2.]]).into_iter();
+    iter.next();
+    assert_eq!(iter.len(), 1);
+    assert_eq!(Matrix::new([0, 0]).iter().len(), 0);
+}
+
+#[test]
+fn matrix_indexed_iter() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+    let indexed: Vec<((usize, usize), f64)> = matrix
+        .indexed_iter()
+        .map(|(position, value)| (position, *value))
+        .collect();
+    assert_eq!(
+        indexed,
+        vec![
+            ((0, 0), 1.),
+            ((0, 1), 2.),
+            ((1, 0), 3.),
+            ((1, 1), 4.),
+            ((2, 0), 5.),
+            ((2, 1), 6.),
+        ]
+    );
+    assert_eq!(matrix.indexed_iter().len(), 6);
+}
+
+#[test]
+fn matrix_rows_cols() {
+    let matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    let rows: Vec<&[f64]> = matrix.rows().collect();
+    assert_eq!(rows, vec![&[1., 2., 3.][..], &[4., 5., 6.][..]]);
+    assert_eq!(matrix.rows().len(), 2);
+    assert_eq!(matrix.iter_rows().collect::<Vec<&[f64]>>(), rows);
+
+    let cols = matrix.cols();
+    assert_eq!(cols.len(), 3);
+    let sums: Vec<f64> = cols.map(|column| column.iter().sum()).collect();
+    assert_eq!(sums, vec![5., 7., 9.]);
+
+    let last = matrix.cols().next_back().unwrap();
+    assert_eq!(last.len(), 2);
+    assert_eq!(last[1], 6.);
+    assert_eq!(last.get(0), Some(&3.));
+    assert_eq!(last.get(2), None);
+    assert_eq!(
+        last.into_iter().copied().collect::<Vec<f64>>(),
+        vec![3., 6.]
+    );
+}
+
+#[test]
+fn matrix_iter_cols_non_square() {
+    let matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    let mut iter = matrix.iter_cols();
+    assert_eq!(iter.len(), 6);
+    iter.next();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.collect::<Vec<f64>>(), vec![4., 2., 5., 3., 6.]);
+}
+
+#[test]
+fn matrix_iter_tuple_non_square() {
+    let a = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    let b = Matrix::from([[6., 5., 4.], [3., 2., 1.]]);
+    let pairs: Vec<[f64; 2]> = a.iter_with(&b).unwrap().collect();
+    assert_eq!(pairs.len(), 6);
+    assert_eq!(pairs[5], [6., 1.]);
+
+    let tall = a.transpose();
+    let pairs = Matrix::iter_tuple(&tall, &tall).unwrap();
+    assert_eq!(pairs.len(), 6);
+    assert_eq!(
+        pairs.map(|[a, _]| a).collect::<Vec<f64>>(),
+        vec![1., 4., 2., 5., 3., 6.]
+    );
+    assert!(a.iter_with(&tall).is_err());
+}
+
+#[test]
+fn vector_iter() {
+    let mut vector = Vector::from([1., 2., 3.]);
+    assert_eq!(vector.iter().len(), 3);
+    for value in vector.iter_mut() {
+        *value *= 10.;
+    }
+    for value in &mut vector {
+        *value += 1.;
+    }
+    assert_eq!(
+        (&vector).into_iter().copied().collect::<Vec<f64>>(),
+        vec![11., 21., 31.]
+    );
+
+    // A single row, and one element in each column
+    assert_eq!(
+        vector.iter_rows().collect::<Vec<&[f64]>>(),
+        vec![&[11., 21., 31.][..]]
+    );
+    assert_eq!(vector.iter_cols().len(), 3);
+    assert_eq!(Vector::new(0).iter_rows().len(), 0);
+
+    let doubled: Vector = vector.into_iter().map(|value| value * 2.).collect();
+    assert_eq!(doubled.all(), &vec![22., 42., 62.]);
+    let range: Vector = (1..=3).map(f64::from).collect();
+    assert_eq!(range.all(), &vec![1., 2., 3.]);
+}
+
+#[test]
+fn matrix_iter_ragged() {
+    // Rows of different sizes can still be built with `From`
+    let ragged = Matrix::from(vec![vec![1.], vec![2., 3.]]);
+    assert_eq!(ragged.iter().len(), 3);
+    assert_eq!(ragged.iter().count(), 3);
+    assert_eq!(ragged.iter().rev().count(), 3);
+    assert_eq!(ragged.clone().into_iter().len(), 3);
+    let positions: Vec<(usize, usize)> = ragged
+        .indexed_iter()
+        .map(|(position, _)| position)
+        .collect();
+    assert_eq!(positions, vec![(0, 0), (1, 0), (1, 1)]);
+    assert_eq!(ragged.indexed_iter().len(), 3);
+    assert_eq!(ragged.sum(), 6.);
+    assert_eq!(ragged.argmax(), Some([1, 1]));
+}
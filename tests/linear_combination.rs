@@ -8,10 +8,7 @@ fn linear_combination_example_1() {
     let e3 = Vector::from([0., 0., 1.]);
 
     let result = linear_combination(&[e1, e2, e3], &[10., -2., 0.5]);
-    match result {
-        Ok(_) => assert!(true),
-        Err(_) => assert!(false, "Failed to calculate linear combination"),
-    }
+    assert!(result.is_ok(), "Failed to calculate linear combination");
 
     let expected = &vec![10., -2., 0.5];
     assert_eq!(result.unwrap().all(), expected);
@@ -23,10 +20,11 @@ fn linear_combination_example_2() {
     let v2 = Vector::from([0., 10., -100.]);
 
     let result = linear_combination(&[v1, v2], &[10., -2.]);
-    match result {
-        Ok(_) => assert!(true),
-        Err(ref err) => assert!(false, "Failed to calculate linear combination: {:#?}", err),
-    }
+    assert!(
+        result.is_ok(),
+        "Failed to calculate linear combination: {:#?}",
+        result
+    );
 
     let expected = &vec![10., 0., 230.];
     assert_eq!(result.unwrap().all(), expected);
@@ -38,10 +36,10 @@ fn invalid_linear_combination_vectors() {
     let v2 = Vector::from([0., 10., -100.]);
 
     let result = linear_combination(&[v1, v2], &[42., 0.]);
-    match result {
-        Ok(_) => assert!(false, "Failed to fail to calculate linear combination"),
-        Err(_) => assert!(true),
-    }
+    assert!(
+        result.is_err(),
+        "Failed to fail to calculate linear combination"
+    );
 }
 
 #[test]
@@ -50,8 +48,8 @@ fn invalid_linear_combination_coeffs() {
     let v2 = Vector::from([0., 10., -100.]);
 
     let result = linear_combination(&[v1, v2], &[42., 0., 42.]);
-    match result {
-        Ok(_) => assert!(false, "Failed to fail to calculate linear combination"),
-        Err(_) => assert!(true),
-    }
+    assert!(
+        result.is_err(),
+        "Failed to fail to calculate linear combination"
+    );
 }
@@ -0,0 +1,131 @@
+use matrix::{matrix::Matrix, permutation::Permutation};
+
+#[test]
+fn matrix_insert() {
+    let mut matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    matrix.insert_row(1, vec![5., 6.]).unwrap();
+    assert_eq!(
+        matrix.all(),
+        &vec![vec![1., 2.], vec![5., 6.], vec![3., 4.]]
+    );
+    matrix.insert_col(2, vec![7., 8., 9.]).unwrap();
+    assert_eq!(
+        matrix.all(),
+        &vec![vec![1., 2., 7.], vec![5., 6., 8.], vec![3., 4., 9.]]
+    );
+
+    assert!(matrix.insert_row(0, vec![1.]).is_err());
+    assert!(matrix.insert_row(4, vec![1., 2., 3.]).is_err());
+    assert!(matrix.insert_col(0, vec![1., 2.]).is_err());
+    assert!(matrix.insert_col(4, vec![1., 2., 3.]).is_err());
+    assert_eq!(matrix.shape(), [3, 3]);
+
+    let mut empty = Matrix::new([0, 0]);
+    empty.insert_col(0, vec![1., 2.]).unwrap();
+    assert_eq!(empty.all(), &vec![vec![1.], vec![2.]]);
+    let mut empty = Matrix::new([0, 0]);
+    empty.insert_row(0, vec![1., 2.]).unwrap();
+    assert_eq!(empty.all(), &vec![vec![1., 2.]]);
+}
+
+#[test]
+fn matrix_remove() {
+    let mut matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+    assert_eq!(matrix.remove_row(1).unwrap(), vec![4., 5., 6.]);
+    assert_eq!(matrix.remove_col(0).unwrap(), vec![1., 7.]);
+    assert_eq!(matrix.all(), &vec![vec![2., 3.], vec![8., 9.]]);
+    assert!(matrix.remove_row(2).is_err());
+    assert!(matrix.remove_col(2).is_err());
+}
+
+#[test]
+fn matrix_swap() {
+    let mut matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    matrix.swap_rows(0, 1);
+    matrix.swap_cols(0, 2);
+    assert_eq!(matrix.all(), &vec![vec![6., 5., 4.], vec![3., 2., 1.]]);
+}
+
+#[test]
+#[should_panic]
+fn matrix_swap_out_of_bounds() {
+    Matrix::from([[1., 2.]]).swap_cols(0, 2);
+}
+
+#[test]
+fn matrix_select() {
+    let matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+    assert_eq!(
+        matrix.select_rows(&[2, 0, 2]).unwrap().all(),
+        &vec![vec![7., 8., 9.], vec![1., 2., 3.], vec![7., 8., 9.]]
+    );
+    assert_eq!(
+        matrix.select_cols(&[1]).unwrap().all(),
+        &vec![vec![2.], vec![5.], vec![8.]]
+    );
+    assert!(matrix.select_rows(&[3]).is_err());
+    assert!(matrix.select_cols(&[0, 3]).is_err());
+}
+
+#[test]
+fn matrix_permute_rows() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+    let permutation = Permutation::new(vec![2, 0, 1]).unwrap();
+    let permuted = matrix.permute_rows(&permutation).unwrap();
+    assert_eq!(
+        permuted.all(),
+        &vec![vec![5., 6.], vec![1., 2.], vec![3., 4.]]
+    );
+    // Same as the product with the permutation matrix
+    assert_eq!(
+        permutation.to_matrix().mul_mat(&matrix).all(),
+        permuted.all()
+    );
+    assert_eq!(
+        permuted.permute_rows(&permutation.inverse()).unwrap().all(),
+        matrix.all()
+    );
+    assert!(matrix.permute_rows(&Permutation::identity(2)).is_err());
+}
+
+#[test]
+fn permutation() {
+    assert!(Permutation::new(vec![0, 0, 1]).is_err());
+    assert!(Permutation::new(vec![0, 3, 1]).is_err());
+    assert!(Permutation::try_from(vec![1, 0]).is_ok());
+
+    let permutation = Permutation::new(vec![1, 2, 0]).unwrap();
+    assert_eq!(permutation.inverse().indices(), &[2, 0, 1]);
+    assert_eq!(
+        permutation.compose(&permutation.inverse()).unwrap(),
+        Permutation::identity(3)
+    );
+    assert!(permutation.compose(&Permutation::identity(2)).is_err());
+    assert_eq!(permutation.to_string(), "[1, 2, 0]");
+
+    // The sign is the determinant of the permutation matrix
+    for indices in [vec![0, 1, 2], vec![1, 0, 2], vec![1, 2, 0], vec![2, 1, 0]] {
+        let permutation = Permutation::new(indices).unwrap();
+        assert_eq!(
+            permutation.sign() as f64,
+            permutation.to_matrix::<f64>().determinant()
+        );
+    }
+
+    let mut swapped = Permutation::identity(3);
+    swapped.swap(0, 2);
+    assert_eq!(swapped.indices(), &[2, 1, 0]);
+    assert_eq!(swapped.sign(), -1);
+}
+
+#[test]
+fn matrix_resize() {
+    let mut matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    matrix.resize([3, 3], -1.);
+    assert_eq!(
+        matrix.all(),
+        &vec![vec![1., 2., -1.], vec![3., 4., -1.], vec![-1., -1., -1.]]
+    );
+    matrix.resize([1, 2], 0.);
+    assert_eq!(matrix.all(), &vec![vec![1., 2.]]);
+}
@@ -65,10 +65,22 @@ fn matrix_matrix_multiplication_example_3() {
 }
 
 #[test]
-fn matrix_matrix_multiplication_invalid() {
+fn matrix_matrix_multiplication_non_square() {
     let matrix = Matrix::from([[3., -5.], [6., 8.]]);
     let other_matrix = Matrix::from([[2., 1., 2.], [4., 2., 2.]]);
 
+    let expected = &vec![vec![-14., -7., -4.], vec![44., 22., 28.]];
+    assert_eq!(matrix.mul_mat(&other_matrix).all(), expected);
+    let column = Matrix::from(vec![vec![1.], vec![2.], vec![3.]]);
+    let expected = &vec![vec![10.], vec![14.]];
+    assert_eq!(other_matrix.mul_mat(&column).all(), expected);
+}
+
+#[test]
+fn matrix_matrix_multiplication_invalid() {
+    let matrix = Matrix::from([[3., -5.], [6., 8.]]);
+    let other_matrix = Matrix::from([[2., 1.], [4., 2.], [2., 2.]]);
+
     let expected: &Vec<Vec<f64>> = &vec![];
     assert_eq!(matrix.mul_mat(&other_matrix).all(), expected);
 }
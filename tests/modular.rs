@@ -0,0 +1,106 @@
+use matrix::{matrix::Matrix, modular::Zp, vector::Vector};
+
+type Z7 = Zp<7>;
+type Z29 = Zp<29>;
+
+fn modular_matrix<const P: u64, const N: usize, const M: usize>(
+    rows: [[i64; N]; M],
+) -> Matrix<Zp<P>> {
+    Matrix::from(
+        rows.iter()
+            .map(|row| row.iter().map(|value| Zp::from(*value)).collect())
+            .collect::<Vec<Vec<Zp<P>>>>(),
+    )
+}
+
+#[test]
+fn zp_arithmetic() {
+    assert_eq!(Z7::new(-1).value(), 6);
+    assert_eq!(Z7::new(15), Z7::new(1));
+    assert_eq!(Z7::new(5) + Z7::new(4), Z7::new(2));
+    assert_eq!(Z7::new(2) - Z7::new(5), Z7::new(4));
+    assert_eq!(Z7::new(3) * Z7::new(5), Z7::new(1));
+    assert_eq!(Z7::new(1) / Z7::new(3), Z7::new(5));
+    assert_eq!(-Z7::new(0), Z7::new(0));
+    assert_eq!(Z7::new(3).pow(6), Z7::new(1));
+    assert_eq!(Z7::new(0).inverse(), None);
+    assert_eq!(Z7::new(6).to_string(), "6");
+    let large = Zp::<18446744073709551557>::new(-2);
+    assert_eq!((large * large).value(), 4);
+}
+
+#[test]
+fn zp_matrix_inverse() {
+    // Hill cipher key over an alphabet of 29 symbols
+    let key = modular_matrix::<29, 2, 2>([[3, 3], [2, 5]]);
+    let inverse = key.inverse().unwrap();
+    assert_eq!(
+        inverse.all(),
+        modular_matrix::<29, 2, 2>([[7, 19], [3, 10]]).all()
+    );
+    assert_eq!(
+        key.mul_mat(&inverse).all(),
+        Matrix::identity(2, Z29::new(1)).all()
+    );
+
+    let message = Vector::from(vec![Z29::new(7), Z29::new(8)]);
+    let encrypted = key.mul_vec(&message);
+    assert_eq!(encrypted.all(), &vec![Z29::new(16), Z29::new(25)]);
+    assert_eq!(inverse.mul_vec(&encrypted).all(), message.all());
+}
+
+#[test]
+fn zp_matrix_determinant_and_rank() {
+    let matrix = modular_matrix::<7, 3, 3>([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+    assert_eq!(matrix.determinant(), Z7::new(-3));
+    assert_eq!(matrix.rank(), 3);
+
+    // Invertible over the rationals but singular modulo 7
+    let singular = modular_matrix::<7, 2, 2>([[1, 2], [3, 13]]);
+    assert_eq!(singular.determinant(), Z7::new(0));
+    assert_eq!(singular.rank(), 1);
+    assert!(singular.inverse().is_err());
+    assert_eq!(
+        singular.row_echelon().all(),
+        modular_matrix::<7, 2, 2>([[1, 2], [0, 0]]).all()
+    );
+}
+
+#[test]
+fn zp_matrix_null_space() {
+    let matrix = modular_matrix::<7, 4, 2>([[1, 2, 0, 3], [2, 4, 1, 1]]);
+    let basis = matrix.null_space();
+    assert_eq!(basis.len(), 2);
+    for vector in basis.iter() {
+        assert!(matrix
+            .mul_vec(vector)
+            .all()
+            .iter()
+            .all(|value| value.value() == 0));
+    }
+    assert_eq!(
+        basis[0].all(),
+        &vec![Z7::new(-2), Z7::new(1), Z7::new(0), Z7::new(0)]
+    );
+}
+
+#[test]
+fn zp_from_matrix() {
+    let matrix = Matrix::from([[8., -1.], [14., 3.]]);
+    let modular = matrix.to_modular::<7>().unwrap();
+    assert_eq!(
+        modular.all(),
+        modular_matrix::<7, 2, 2>([[1, 6], [0, 3]]).all()
+    );
+    assert!(Matrix::from([[0.5]]).to_modular::<7>().is_err());
+}
+
+#[test]
+fn f64_matrix_null_space() {
+    let matrix = Matrix::from([[1., 2., 3.], [2., 4., 6.]]);
+    let basis = matrix.null_space();
+    assert_eq!(basis.len(), 2);
+    assert_eq!(basis[0].all(), &vec![-2., 1., 0.]);
+    assert_eq!(basis[1].all(), &vec![-3., 0., 1.]);
+    assert!(Matrix::identity(3, 1.).null_space().is_empty());
+}
@@ -0,0 +1,161 @@
+use matrix::io::{
+    npy::{
+        read_npy_matrix, read_npy_vector, write_npy_matrix, write_npy_vector, NpyOptions, NpyType,
+    },
+    Endian,
+};
+use matrix::matrix::Matrix;
+use matrix::vector::Vector;
+
+// Build a version 1 file with the given header and raw values
+fn npy_file(header: &str, values: &[u8]) -> Vec<u8> {
+    let mut header = header.to_string();
+    let padding = 64 - (10 + header.len() + 1) % 64;
+    header.push_str(&" ".repeat(padding % 64));
+    header.push('\n');
+    let mut file = b"\x93NUMPY\x01\x00".to_vec();
+    file.extend((header.len() as u16).to_le_bytes());
+    file.extend(header.as_bytes());
+    file.extend(values);
+    file
+}
+
+#[test]
+fn npy_read_matrix_f64() {
+    let values: Vec<u8> = [1., 2., 3., 4., 5., 6.]
+        .iter()
+        .flat_map(|value: &f64| value.to_le_bytes())
+        .collect();
+    let file = npy_file(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }",
+        &values,
+    );
+    let matrix = read_npy_matrix(file.as_slice()).unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+}
+
+#[test]
+fn npy_read_matrix_fortran_big_endian_i32() {
+    let values: Vec<u8> = [1, 4, 2, 5, 3, 6]
+        .iter()
+        .flat_map(|value: &i32| value.to_be_bytes())
+        .collect();
+    let file = npy_file(
+        "{'descr': '>i4', 'fortran_order': True, 'shape': (2, 3), }",
+        &values,
+    );
+    let matrix = read_npy_matrix(file.as_slice()).unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+}
+
+#[test]
+fn npy_read_vector() {
+    let values: Vec<u8> = [1.5, -2.]
+        .iter()
+        .flat_map(|value: &f32| value.to_le_bytes())
+        .collect();
+    let file = npy_file(
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (2,), }",
+        &values,
+    );
+    let vector = read_npy_vector(file.as_slice()).unwrap();
+    assert_eq!(vector.all(), &vec![1.5, -2.]);
+}
+
+#[test]
+fn npy_read_invalid() {
+    let file = npy_file(
+        "{'descr': '<c16', 'fortran_order': False, 'shape': (1,), }",
+        &[0; 16],
+    );
+    let error = read_npy_matrix(file.as_slice()).unwrap_err();
+    assert_eq!(error.to_string(), "Unsupported dtype '<c16'");
+
+    let file = npy_file(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 1, 1), }",
+        &[0; 8],
+    );
+    let error = read_npy_matrix(file.as_slice()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unsupported rank 3 for shape [1, 1, 1], expected at most 2"
+    );
+
+    assert!(read_npy_matrix(b"not a npy file".as_slice()).is_err());
+}
+
+#[test]
+fn npy_read_invalid_shape() {
+    // The number of bytes overflows
+    let file = npy_file(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (4294967296, 4294967296), }",
+        &[0; 8],
+    );
+    let error = read_npy_matrix(file.as_slice()).unwrap_err();
+    assert_eq!(error.to_string(), "Invalid shape [4294967296, 4294967296]");
+
+    // Far more values than the file contains
+    let file = npy_file(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (100000, 1000000), }",
+        &[0; 16],
+    );
+    let error = read_npy_matrix(file.as_slice()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Expected 800000000000 bytes of data for shape [100000, 1000000], got 16"
+    );
+
+    // No data, but too many rows to allocate
+    let file = npy_file(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1152921504606846976, 0), }",
+        &[],
+    );
+    let error = read_npy_matrix(file.as_slice()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Matrix of shape [1152921504606846976, 0] is too large, at most 268435456 elements are supported"
+    );
+}
+
+#[test]
+fn npy_round_trip() {
+    let matrix = Matrix::from([[1., -2., 3.], [4., 5., -6.]]);
+    for dtype in [NpyType::F32, NpyType::F64, NpyType::I32, NpyType::I64] {
+        for endian in [Endian::Little, Endian::Big] {
+            for fortran_order in [false, true] {
+                let options = NpyOptions {
+                    dtype,
+                    endian,
+                    fortran_order,
+                };
+                let mut buffer = vec![];
+                write_npy_matrix(&mut buffer, &matrix, &options).unwrap();
+                let read = read_npy_matrix(buffer.as_slice()).unwrap();
+                assert_eq!(read.all(), matrix.all());
+            }
+        }
+    }
+}
+
+#[test]
+fn npy_write_header() {
+    let vector = Vector::from([1., 2.]);
+    let mut buffer = vec![];
+    write_npy_vector(&mut buffer, &vector, &NpyOptions::default()).unwrap();
+    assert_eq!(&buffer[..8], b"\x93NUMPY\x01\x00");
+    let length = u16::from_le_bytes([buffer[8], buffer[9]]) as usize;
+    assert_eq!((10 + length) % 64, 0);
+    let header = std::str::from_utf8(&buffer[10..10 + length]).unwrap();
+    assert!(header.starts_with("{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }"));
+    assert!(header.ends_with('\n'));
+}
+
+#[test]
+fn npy_write_invalid_integer() {
+    let vector = Vector::from([1.5]);
+    let options = NpyOptions {
+        dtype: NpyType::I32,
+        ..Default::default()
+    };
+    assert!(write_npy_vector(vec![], &vector, &options).is_err());
+}
@@ -0,0 +1,94 @@
+use matrix::matrix::Matrix;
+use matrix::parse::ParseError;
+use matrix::vector::Vector;
+
+#[test]
+fn parse_matrix_matlab() {
+    let matrix: Matrix = "[1 2; 3 4]".parse().unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2.], vec![3., 4.]]);
+}
+
+#[test]
+fn parse_matrix_nested() {
+    let matrix: Matrix = "[[1, 2.5], [-3, 4e2]]".parse().unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2.5], vec![-3., 400.]]);
+}
+
+#[test]
+fn parse_matrix_nested_multiline() {
+    let matrix: Matrix = "[\n  [1, 2],\n  [3, 4],\n]".parse().unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2.], vec![3., 4.]]);
+}
+
+#[test]
+fn parse_matrix_rows() {
+    let matrix: Matrix = "1 2 3\n4 5 6\n".parse().unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    let matrix: Matrix = "1,2,3\n4,5,6".parse().unwrap();
+    assert_eq!(matrix.all(), &vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+}
+
+#[test]
+fn parse_matrix_round_trip() {
+    let matrix = Matrix::from([[1., -2.], [0.5, 4.]]);
+    let parsed: Matrix = format!("{:#}", matrix).parse().unwrap();
+    assert_eq!(parsed.all(), matrix.all());
+}
+
+#[test]
+fn parse_matrix_empty() {
+    let matrix: Matrix = "[]".parse().unwrap();
+    assert_eq!(matrix.shape(), [0, 0]);
+}
+
+#[test]
+fn parse_matrix_ragged() {
+    let error = "[1 2; 3 4 5]".parse::<Matrix>().unwrap_err();
+    assert_eq!(
+        error,
+        ParseError {
+            line: 1,
+            column: 7,
+            message: "Row 2 has 3 columns, expected 2".to_string()
+        }
+    );
+}
+
+#[test]
+fn parse_matrix_invalid_number() {
+    let error = "1 2\n3 x4".parse::<Matrix>().unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.to_string(), "Invalid number 'x4' at line 2, column 3");
+}
+
+#[test]
+fn parse_matrix_unclosed() {
+    let error = "[[1, 2], [3, 4]".parse::<Matrix>().unwrap_err();
+    assert_eq!((error.line, error.column), (1, 16));
+}
+
+#[test]
+fn parse_vector() {
+    let vector: Vector = "[1 2 3]".parse().unwrap();
+    assert_eq!(vector.all(), &vec![1., 2., 3.]);
+    let vector: Vector = "1; 2; 3".parse().unwrap();
+    assert_eq!(vector.all(), &vec![1., 2., 3.]);
+}
+
+#[test]
+fn parse_vector_invalid_shape() {
+    assert!("[1 2; 3 4]".parse::<Vector>().is_err());
+
+    // Reported at the second row
+    let error = "1 2\n3 4\n".parse::<Vector>().unwrap_err();
+    assert_eq!(
+        error,
+        ParseError {
+            line: 2,
+            column: 1,
+            message: "Expected a single row or column, got shape [2, 2]".to_string()
+        }
+    );
+    let error = "[[1, 2], [3, 4]]".parse::<Vector>().unwrap_err();
+    assert_eq!((error.line, error.column), (1, 10));
+}
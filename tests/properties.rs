@@ -0,0 +1,100 @@
+use matrix::{matrix::Matrix, rational::Rational};
+
+#[test]
+fn matrix_square_diagonal_identity() {
+    assert!(Matrix::from([[1., 2.], [3., 4.]]).is_square());
+    assert!(!Matrix::from([[1., 2.]]).is_square());
+
+    assert!(Matrix::from([[1., 0.], [0., -3.]]).is_diagonal());
+    assert!(!Matrix::from([[1., 0.], [0.1, -3.]]).is_diagonal());
+    assert!(!Matrix::from([[1., 0.]]).is_diagonal());
+
+    assert!(Matrix::identity(3, 1.).is_identity());
+    assert!(!Matrix::identity(3, 2.).is_identity());
+    assert!(!Matrix::from([[1., 0.]]).is_identity());
+    assert!(Matrix::identity(2, Rational::from(1)).is_identity());
+}
+
+#[test]
+fn matrix_triangular() {
+    let upper = Matrix::from([[1., 2., 3.], [0., 4., 5.], [0., 0., 6.]]);
+    assert!(upper.is_upper_triangular());
+    assert!(!upper.is_lower_triangular());
+    assert!(upper.transpose().is_lower_triangular());
+    assert!(Matrix::from([[1., 2., 3.], [0., 4., 5.]]).is_upper_triangular());
+    // Row echelon forms are upper triangular
+    let matrix = Matrix::from([[2., 1., -1.], [-3., -1., 2.], [-2., 1., 2.]]);
+    assert!(matrix.row_echelon().is_upper_triangular());
+    assert!(!matrix.is_upper_triangular());
+    let diagonal = Matrix::from([[1., 0.], [0., 2.]]);
+    assert!(diagonal.is_upper_triangular() && diagonal.is_lower_triangular());
+}
+
+#[test]
+fn matrix_symmetric() {
+    let symmetric = Matrix::from([[1., 2.], [2., 3.]]);
+    assert!(symmetric.is_symmetric(0.));
+    assert!(!Matrix::from([[1., 2.], [2.1, 3.]]).is_symmetric(0.));
+    assert!(Matrix::from([[1., 2.], [2.1, 3.]]).is_symmetric(0.2));
+    assert!(!Matrix::from([[1., 2.]]).is_symmetric(1.));
+
+    let skew = Matrix::from([[0., 2., -1.], [-2., 0., 3.], [1., -3., 0.]]);
+    assert!(skew.is_skew_symmetric(0.));
+    assert!(!skew.is_symmetric(0.));
+    assert!(!symmetric.is_skew_symmetric(0.));
+    assert!(!Matrix::from([[1., 0.], [0., 0.]]).is_skew_symmetric(0.));
+}
+
+#[test]
+fn matrix_orthogonal() {
+    let angle: f64 = 0.3;
+    let rotation = Matrix::from([[angle.cos(), -angle.sin()], [angle.sin(), angle.cos()]]);
+    assert!(rotation.is_orthogonal(1e-12));
+    let permutation = Matrix::from([[0., 1., 0.], [0., 0., 1.], [1., 0., 0.]]);
+    assert!(permutation.is_orthogonal(0.));
+    assert!(!Matrix::from([[1., 1.], [0., 1.]]).is_orthogonal(1e-12));
+    assert!(!Matrix::from([[1., 0.]]).is_orthogonal(1e-12));
+}
+
+#[test]
+fn matrix_positive_definite() {
+    assert!(Matrix::from([[4., 1.], [1., 3.]]).is_positive_definite());
+    assert!(Matrix::from([[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]).is_positive_definite());
+    // Indefinite, semi-definite and non-symmetric matrices
+    assert!(!Matrix::from([[1., 2.], [2., 1.]]).is_positive_definite());
+    assert!(!Matrix::from([[1., 1.], [1., 1.]]).is_positive_definite());
+    assert!(!Matrix::from([[2., 1.], [0., 2.]]).is_positive_definite());
+    assert!(!Matrix::new([0, 0]).is_positive_definite());
+}
+
+#[test]
+fn matrix_singular_invertible() {
+    let invertible = Matrix::from([[1., 2.], [3., 4.]]);
+    assert!(invertible.is_invertible());
+    assert!(!invertible.is_singular(1e-12));
+
+    let singular = Matrix::from([[1., 2.], [2., 4.]]);
+    assert!(!singular.is_invertible());
+    assert!(singular.is_singular(1e-12));
+
+    // Nearly singular, only detected with a tolerance
+    let nearly = Matrix::from([[1., 2.], [1., 2. + 1e-13]]);
+    assert!(!nearly.is_invertible());
+    assert!(nearly.is_singular(Matrix::SINGULAR_TOLERANCE));
+    assert!(nearly.is_singular(1e-10));
+    assert!(!nearly.is_singular(0.));
+
+    // The tolerance is relative to the largest element, the scale doesn't matter
+    let small = Matrix::from([[1e-10, 0.], [0., 1e-10]]);
+    assert!(small.is_invertible());
+    assert!(!small.is_singular(Matrix::SINGULAR_TOLERANCE));
+    let large = Matrix::from([[1e10, 2e10], [2e10, 4e10 + 1e-3]]);
+    assert!(!large.is_invertible());
+    assert!(large.is_singular(Matrix::SINGULAR_TOLERANCE));
+
+    assert!(!Matrix::from([[1., 2.]]).is_invertible());
+    assert!(Matrix::from([[1., 2.]]).is_singular(0.));
+
+    let exact = Matrix::from([[1, 2], [2, 4]].map(|row| row.map(Rational::from)));
+    assert!(!exact.is_invertible());
+}
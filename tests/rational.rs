@@ -0,0 +1,164 @@
+use matrix::{matrix::Matrix, rational::Rational, vector::Vector};
+
+fn rational_matrix(rows: &[&[&str]]) -> Matrix<Rational> {
+    Matrix::from(
+        rows.iter()
+            .map(|row| row.iter().map(|value| value.parse().unwrap()).collect())
+            .collect::<Vec<Vec<Rational>>>(),
+    )
+}
+
+#[test]
+fn rational_normalisation() {
+    let value = Rational::new(6, -4);
+    assert_eq!(value.numerator(), -3);
+    assert_eq!(value.denominator(), 2);
+    assert_eq!(value, Rational::new(-3, 2));
+    assert_eq!(Rational::new(0, -5), Rational::from(0));
+    assert_eq!(Rational::checked_new(1, 0), None);
+}
+
+#[test]
+fn rational_arithmetic() {
+    let half = Rational::new(1, 2);
+    let third = Rational::new(1, 3);
+    assert_eq!(half + third, Rational::new(5, 6));
+    assert_eq!(half - third, Rational::new(1, 6));
+    assert_eq!(half * third, Rational::new(1, 6));
+    assert_eq!(half / third, Rational::new(3, 2));
+    assert_eq!(-half, Rational::new(-1, 2));
+    assert_eq!(third.recip(), Rational::from(3));
+}
+
+#[test]
+fn rational_overflow() {
+    let large = Rational::from(i128::MAX);
+    assert_eq!(large.checked_add(&Rational::from(1)), None);
+    assert_eq!(large.checked_mul(&Rational::from(2)), None);
+    assert_eq!(
+        large.checked_mul(&Rational::new(1, 2)),
+        Some(Rational::new(i128::MAX, 2))
+    );
+    assert!(std::panic::catch_unwind(|| large + Rational::from(1)).is_err());
+}
+
+#[test]
+fn rational_ordering() {
+    assert!(Rational::new(1, 3) < Rational::new(1, 2));
+    assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+    assert!(Rational::new(7, 5) > Rational::from(1));
+    let [a, b] = [
+        Rational::new(i128::MAX - 1, i128::MAX),
+        Rational::new(i128::MAX - 2, i128::MAX - 1),
+    ];
+    assert!(a > b);
+    assert_eq!(a.cmp(&a), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn rational_parse_and_display() {
+    assert_eq!("3/2".parse::<Rational>().unwrap(), Rational::new(3, 2));
+    assert_eq!("-0.75".parse::<Rational>().unwrap(), Rational::new(-3, 4));
+    assert_eq!("4".parse::<Rational>().unwrap(), Rational::from(4));
+    assert!("1/0".parse::<Rational>().is_err());
+    assert!("a".parse::<Rational>().is_err());
+    assert_eq!(Rational::new(-1, 2).to_string(), "-1/2");
+    assert_eq!(Rational::from(-2).to_string(), "-2");
+}
+
+#[test]
+fn rational_from_f64() {
+    assert_eq!(Rational::try_from(0.5).unwrap(), Rational::new(1, 2));
+    assert_eq!(Rational::try_from(-3.).unwrap(), Rational::from(-3));
+    assert_eq!(
+        Rational::try_from(0.1).unwrap(),
+        Rational::new(3602879701896397, 36028797018963968)
+    );
+    assert!(Rational::try_from(f64::NAN).is_err());
+    assert!(Rational::try_from(1e300).is_err());
+}
+
+#[test]
+fn rational_matrix_inverse() {
+    let matrix = rational_matrix(&[&["1", "2"], &["3", "4"]]);
+    let expected = rational_matrix(&[&["-2", "1"], &["3/2", "-1/2"]]);
+    assert_eq!(matrix.inverse().unwrap().all(), expected.all());
+    assert_eq!(
+        format!("{:#}", matrix.inverse().unwrap()),
+        "[-2, 1; 3/2, -1/2]"
+    );
+    let (_, steps) = matrix.inverse_steps().unwrap();
+    assert_eq!(steps[0].operation.to_string(), "R2 ← R2 - 3·R1");
+    assert_eq!(steps[1].operation.to_string(), "R2 ← -1/2·R2");
+}
+
+#[test]
+fn rational_matrix_inverse_without_rounding() {
+    // Inverse of the Hilbert matrix of size 4, which only has integer values
+    let matrix = Matrix::from(
+        (1..=4)
+            .map(|i| (1..=4).map(|j| Rational::new(1, i + j - 1)).collect())
+            .collect::<Vec<Vec<Rational>>>(),
+    );
+    let expected: Vec<Vec<Rational>> = [
+        [16, -120, 240, -140],
+        [-120, 1200, -2700, 1680],
+        [240, -2700, 6480, -4200],
+        [-140, 1680, -4200, 2800],
+    ]
+    .iter()
+    .map(|row| row.iter().map(|value| Rational::from(*value)).collect())
+    .collect();
+    let inverse = matrix.inverse().unwrap();
+    assert_eq!(inverse.all(), &expected);
+    assert_eq!(
+        matrix.mul_mat(&inverse).all(),
+        Matrix::identity(4, Rational::from(1)).all()
+    );
+}
+
+#[test]
+fn rational_matrix_inverse_single() {
+    let matrix = rational_matrix(&[&["2/3"]]);
+    assert_eq!(
+        matrix.inverse().unwrap().all(),
+        rational_matrix(&[&["3/2"]]).all()
+    );
+    assert!(rational_matrix(&[&["0"]]).inverse().is_err());
+}
+
+#[test]
+fn rational_matrix_determinant() {
+    let matrix = rational_matrix(&[&["1/2", "1/3"], &["1/4", "1/5"]]);
+    assert_eq!(matrix.determinant(), Rational::new(1, 60));
+    let singular = rational_matrix(&[&["1/3", "2/3"], &["1", "2"]]);
+    assert_eq!(singular.determinant(), Rational::from(0));
+    assert!(singular.inverse().is_err());
+}
+
+#[test]
+fn rational_matrix_row_echelon_and_rank() {
+    let matrix = rational_matrix(&[&["1", "2", "1"], &["3", "4", "0"], &["1/3", "2/3", "1/3"]]);
+    let expected = rational_matrix(&[&["1", "0", "-2"], &["0", "1", "3/2"], &["0", "0", "0"]]);
+    assert_eq!(matrix.row_echelon().all(), expected.all());
+    assert_eq!(matrix.rank(), 2);
+}
+
+#[test]
+fn rational_matrix_solve() {
+    let matrix = rational_matrix(&[&["2", "1"], &["1", "3"]]);
+    let b = Vector::from(vec![Rational::from(1), Rational::from(2)]);
+    let x = matrix.solve(&b).unwrap();
+    assert_eq!(x.all(), &vec![Rational::new(1, 5), Rational::new(3, 5)]);
+}
+
+#[test]
+fn rational_matrix_conversions() {
+    let matrix = Matrix::from([[0.5, -2.], [0.25, 3.]]);
+    let rational = matrix.to_rational().unwrap();
+    assert_eq!(rational[0][0], Rational::new(1, 2));
+    assert_eq!(rational.to_f64().all(), matrix.all());
+    assert!(Matrix::from([[f64::INFINITY]]).to_rational().is_err());
+    let vector = Vector::from([0.125, 4.]);
+    assert_eq!(vector.to_rational().unwrap().to_f64().all(), vector.all());
+}
@@ -0,0 +1,104 @@
+use matrix::{matrix::Matrix, reduction::Axis, vector::Vector};
+
+fn table() -> Matrix {
+    // 4 measurements of 3 quantities
+    Matrix::from([
+        [1., 10., -2.],
+        [2., 30., -4.],
+        [3., 20., -6.],
+        [6., 40., -8.],
+    ])
+}
+
+#[test]
+fn matrix_sum_mean() {
+    let matrix = table();
+    assert_eq!(matrix.sum(), 92.);
+    assert_eq!(matrix.mean(), 92. / 12.);
+    assert_eq!(matrix.sum_axis(Axis::Columns).all(), &vec![12., 100., -20.]);
+    assert_eq!(matrix.sum_axis(Axis::Rows).all(), &vec![9., 28., 17., 38.]);
+    assert_eq!(matrix.mean_axis(Axis::Columns).all(), &vec![3., 25., -5.]);
+    assert_eq!(
+        matrix.mean_axis(Axis::Rows).all(),
+        &vec![3., 28. / 3., 17. / 3., 38. / 3.]
+    );
+
+    let empty = Matrix::new([0, 0]);
+    assert_eq!(empty.sum(), 0.);
+    assert!(empty.mean().is_nan());
+    assert_eq!(empty.sum_axis(Axis::Rows).size(), 0);
+}
+
+#[test]
+fn matrix_variance() {
+    let matrix = table();
+    assert_eq!(
+        matrix.variance_axis(Axis::Columns).all(),
+        &vec![14. / 3., 500. / 3., 20. / 3.]
+    );
+    assert_eq!(
+        matrix.std_dev_axis(Axis::Columns).all(),
+        &vec![
+            (14_f64 / 3.).sqrt(),
+            (500_f64 / 3.).sqrt(),
+            (20_f64 / 3.).sqrt()
+        ]
+    );
+    assert_eq!(Matrix::from([[1., 3.], [5., 7.]]).variance(), 20. / 3.);
+    assert!(Matrix::from([[1.]]).variance().is_nan());
+}
+
+#[test]
+fn matrix_min_max() {
+    let matrix = table();
+    assert_eq!(matrix.min(), Some(-8.));
+    assert_eq!(matrix.max(), Some(40.));
+    assert_eq!(matrix.argmin(), Some([3, 2]));
+    assert_eq!(matrix.argmax(), Some([3, 1]));
+    assert_eq!(matrix.min_axis(Axis::Columns).all(), &vec![1., 10., -8.]);
+    assert_eq!(matrix.max_axis(Axis::Rows).all(), &vec![10., 30., 20., 40.]);
+    assert_eq!(
+        matrix.argmax_axis(Axis::Columns),
+        vec![Some(3), Some(3), Some(0)]
+    );
+    assert_eq!(
+        matrix.argmin_axis(Axis::Rows),
+        vec![Some(2), Some(2), Some(2), Some(2)]
+    );
+
+    let empty = Matrix::new([0, 0]);
+    assert_eq!(empty.min(), None);
+    assert_eq!(empty.argmax(), None);
+}
+
+#[test]
+fn matrix_min_max_nan() {
+    let matrix = Matrix::from([[f64::NAN, 2.], [2., -1.]]);
+    assert_eq!(matrix.min(), Some(-1.));
+    assert_eq!(matrix.argmax(), Some([0, 1]));
+    assert_eq!(matrix.argmin_axis(Axis::Columns), vec![Some(1), Some(1)]);
+
+    let missing = Matrix::from([[f64::NAN], [f64::NAN]]);
+    assert_eq!(missing.argmin_axis(Axis::Columns), vec![None]);
+    assert!(missing.min_axis(Axis::Columns)[0].is_nan());
+}
+
+#[test]
+fn vector_reductions() {
+    let vector = Vector::from([2., 4., 4., 4., 5., 5., 7., 9.]);
+    assert_eq!(vector.sum(), 40.);
+    assert_eq!(vector.mean(), 5.);
+    assert_eq!(vector.variance(), 32. / 7.);
+    assert_eq!(vector.std_dev(), (32_f64 / 7.).sqrt());
+    assert_eq!(vector.min(), Some(2.));
+    assert_eq!(vector.max(), Some(9.));
+    // First of the equal values
+    assert_eq!(Vector::from([3., 1., 1.]).argmin(), Some(1));
+    assert_eq!(Vector::from([3., 1., 3.]).argmax(), Some(0));
+
+    let empty = Vector::new(0);
+    assert_eq!(empty.sum(), 0.);
+    assert!(empty.mean().is_nan());
+    assert_eq!(empty.argmin(), None);
+    assert_eq!(empty.max(), None);
+}
@@ -0,0 +1,114 @@
+use matrix::{matrix::Matrix, regression, vector::Vector};
+
+fn assert_close(result: &Vector, expected: &[f64], tolerance: f64) {
+    assert_eq!(result.size(), expected.len());
+    for (a, b) in result.iter().zip(expected.iter()) {
+        assert!(
+            (a - b).abs() < tolerance,
+            "{} is not close to {:?}",
+            result,
+            expected
+        );
+    }
+}
+
+#[test]
+fn fit_linear_exact() {
+    // y = 1 + 2 x1 - x2
+    let x = Matrix::from([[0., 1.], [1., 0.], [2., 3.], [3., 1.], [4., 5.]]);
+    let y = Vector::from([0., 3., 2., 6., 4.]);
+    let fit = regression::fit_linear(&x, &y).unwrap();
+    assert_close(&fit.coefficients, &[1., 2., -1.], 1e-12);
+    assert_close(&fit.residuals, &[0.; 5], 1e-12);
+    assert!((fit.r_squared - 1.).abs() < 1e-12);
+    assert_close(&fit.standard_errors, &[0.; 3], 1e-6);
+}
+
+#[test]
+fn fit_linear_noisy() {
+    let x = Matrix::from([[1.], [2.], [3.], [4.], [5.]]);
+    let y = Vector::from([2., 4., 5., 4., 5.]);
+    let fit = regression::fit_linear(&x, &y).unwrap();
+    assert_close(&fit.coefficients, &[2.2, 0.6], 1e-12);
+    assert_close(&fit.residuals, &[-0.8, 0.6, 1., -0.6, -0.2], 1e-12);
+    assert!((fit.r_squared - 0.6).abs() < 1e-12);
+    assert_close(
+        &fit.standard_errors,
+        &[0.88_f64.sqrt(), 0.08_f64.sqrt()],
+        1e-12,
+    );
+}
+
+#[test]
+fn fit_linear_constant() {
+    // Nothing to explain, the coefficient of determination is undefined
+    let x = Matrix::from([[1.], [2.], [3.], [4.]]);
+    let y = Vector::from([3., 3., 3., 3.]);
+    let fit = regression::fit_linear(&x, &y).unwrap();
+    assert_close(&fit.coefficients, &[3., 0.], 1e-12);
+    assert!(fit.r_squared.is_nan());
+}
+
+#[test]
+fn fit_linear_invalid() {
+    let y = Vector::from([1., 2., 3.]);
+    // Different number of observations
+    assert!(regression::fit_linear(&Matrix::from([[1.], [2.]]), &y).is_err());
+    // More coefficients than observations
+    assert!(regression::fit_linear(
+        &Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 0.]]),
+        &y
+    )
+    .is_err());
+    // Colinear features
+    assert!(regression::fit_linear(&Matrix::from([[1., 2.], [2., 4.], [3., 6.]]), &y).is_err());
+}
+
+#[test]
+fn polyfit() {
+    // y = 1 - 2x + 3x^2
+    let xs = Vector::from([-2., -1., 0., 1., 2., 3.]);
+    let ys = Vector::from([17., 6., 1., 2., 9., 22.]);
+    assert_close(
+        &regression::polyfit(&xs, &ys, 2).unwrap(),
+        &[1., -2., 3.],
+        1e-10,
+    );
+    // Higher degree terms vanish
+    assert_close(
+        &regression::polyfit(&xs, &ys, 3).unwrap(),
+        &[1., -2., 3., 0.],
+        1e-10,
+    );
+    // Best line through the points of a parabola
+    let xs = Vector::from([-1., 0., 1.]);
+    let ys = Vector::from([1., 0., 1.]);
+    assert_close(
+        &regression::polyfit(&xs, &ys, 1).unwrap(),
+        &[2. / 3., 0.],
+        1e-12,
+    );
+
+    assert!(regression::polyfit(&xs, &ys, 3).is_err());
+    assert!(regression::polyfit(&xs, &Vector::from([1.]), 1).is_err());
+}
+
+#[test]
+fn ridge() {
+    let x = Matrix::from([[1.], [2.], [3.], [4.], [5.]]);
+    let y = Vector::from([2., 4., 5., 4., 5.]);
+    // Without a penalty, same as the ordinary least squares
+    assert_close(&regression::ridge(&x, &y, 0.).unwrap(), &[2.2, 0.6], 1e-12);
+    // Slope Sxy / (Sxx + lambda)
+    assert_close(&regression::ridge(&x, &y, 5.).unwrap(), &[2.8, 0.4], 1e-12);
+    // The slope vanishes and the intercept tends to the mean
+    assert_close(&regression::ridge(&x, &y, 1e12).unwrap(), &[4., 0.], 1e-9);
+
+    // The penalty makes colinear features solvable
+    let colinear = Matrix::from([[1., 2.], [2., 4.], [3., 6.]]);
+    let coefficients = regression::ridge(&colinear, &Vector::from([1., 2., 3.]), 1.).unwrap();
+    assert!((coefficients[2] - 2. * coefficients[1]).abs() < 1e-12);
+
+    assert!(regression::ridge(&x, &y, -1.).is_err());
+    assert!(regression::ridge(&x, &Vector::from([1.]), 1.).is_err());
+}
@@ -0,0 +1,106 @@
+use matrix::{
+    matrix::Matrix,
+    semiring::{Boolean, MaxPlus, MinPlus},
+    vector::Vector,
+};
+
+const INF: f64 = f64::INFINITY;
+
+fn boolean_matrix<const N: usize, const M: usize>(rows: [[u8; N]; M]) -> Matrix<Boolean> {
+    Matrix::from(
+        rows.iter()
+            .map(|row| row.iter().map(|value| Boolean(*value != 0)).collect())
+            .collect::<Vec<Vec<Boolean>>>(),
+    )
+}
+
+fn min_plus_matrix<const N: usize>(rows: [[f64; N]; N]) -> Matrix<MinPlus> {
+    Matrix::from(
+        rows.iter()
+            .map(|row| row.iter().map(|value| MinPlus(*value)).collect())
+            .collect::<Vec<Vec<MinPlus>>>(),
+    )
+}
+
+#[test]
+fn boolean_reachability() {
+    // 0 -> 1 -> 2 -> 3, and 4 is isolated
+    let adjacency = boolean_matrix([
+        [0, 1, 0, 0, 0],
+        [0, 0, 1, 0, 0],
+        [0, 0, 0, 1, 0],
+        [0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0],
+    ]);
+    let two_steps = adjacency.mul_mat(&adjacency);
+    assert_eq!(two_steps[0][2], Boolean(true));
+    assert_eq!(two_steps[0][1], Boolean(false));
+
+    let expected = boolean_matrix([
+        [1, 1, 1, 1, 0],
+        [0, 1, 1, 1, 0],
+        [0, 0, 1, 1, 0],
+        [0, 0, 0, 1, 0],
+        [0, 0, 0, 0, 1],
+    ]);
+    assert_eq!(adjacency.closure().all(), expected.all());
+    assert_eq!(
+        format!("{:#}", adjacency.closure()),
+        "[1, 1, 1, 1, 0; 0, 1, 1, 1, 0; 0, 0, 1, 1, 0; 0, 0, 0, 1, 0; 0, 0, 0, 0, 1]"
+    );
+}
+
+#[test]
+fn min_plus_shortest_paths() {
+    let weights = min_plus_matrix([
+        [0., 3., INF, 7.],
+        [8., 0., 2., INF],
+        [5., INF, 0., 1.],
+        [2., INF, INF, 0.],
+    ]);
+    let expected = min_plus_matrix([
+        [0., 3., 5., 6.],
+        [5., 0., 2., 3.],
+        [3., 6., 0., 1.],
+        [2., 5., 7., 0.],
+    ]);
+    assert_eq!(weights.closure().all(), expected.all());
+
+    // One relaxation step from the source 0
+    let distances = Vector::from(vec![MinPlus(0.), MinPlus(INF), MinPlus(INF), MinPlus(INF)]);
+    let relaxed = weights.transpose().mul_vec(&distances);
+    assert_eq!(
+        relaxed.all(),
+        &vec![MinPlus(0.), MinPlus(3.), MinPlus(INF), MinPlus(7.)]
+    );
+    assert_eq!(format!("{:#}", relaxed), "[0, 3, ∞, 7]");
+}
+
+#[test]
+fn max_plus_scheduling() {
+    // Durations of the tasks before each other task can start, -∞ if there is no dependency
+    let none = f64::NEG_INFINITY;
+    let durations = Matrix::from(
+        [
+            [none, 2., 3., none],
+            [none, none, none, 4.],
+            [none, none, none, 1.],
+            [none, none, none, none],
+        ]
+        .iter()
+        .map(|row| row.iter().map(|value| MaxPlus(*value)).collect())
+        .collect::<Vec<Vec<MaxPlus>>>(),
+    );
+    // Earliest start of each task is the longest path from the first one
+    let longest = durations.closure();
+    assert_eq!(longest[0][3], MaxPlus(6.));
+    assert_eq!(longest[0][2], MaxPlus(3.));
+    assert_eq!(longest[3][0], MaxPlus(none));
+    assert_eq!(longest[1][1], MaxPlus(0.));
+}
+
+#[test]
+fn semiring_closure_of_non_square_matrix() {
+    let matrix = boolean_matrix([[1, 0, 1]]);
+    assert_eq!(matrix.closure().shape(), [0, 0]);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "serde")]
+
+use matrix::matrix::Matrix;
+use matrix::vector::Vector;
+
+#[test]
+fn serde_vector() {
+    let vector = Vector::from([1., -2.5, 3.]);
+    let json = serde_json::to_string(&vector).unwrap();
+    assert_eq!(json, "[1.0,-2.5,3.0]");
+    let read: Vector = serde_json::from_str(&json).unwrap();
+    assert_eq!(read.all(), vector.all());
+}
+
+#[test]
+fn serde_matrix() {
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    let json = serde_json::to_string(&matrix).unwrap();
+    assert_eq!(json, "[[1.0,2.0],[3.0,4.0]]");
+    let read: Matrix = serde_json::from_str(&json).unwrap();
+    assert_eq!(read.all(), matrix.all());
+}
+
+#[test]
+fn serde_matrix_ragged() {
+    let error = serde_json::from_str::<Matrix>("[[1, 2], [3]]").unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Row 2 has 1 columns, expected 2"));
+}
+
+#[test]
+fn serde_matrix_empty() {
+    let read: Matrix = serde_json::from_str("[]").unwrap();
+    assert_eq!(read.shape(), [0, 0]);
+}
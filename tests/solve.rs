@@ -0,0 +1,35 @@
+use matrix::matrix::Matrix;
+use matrix::vector::Vector;
+
+#[test]
+fn matrix_solve_example_1() {
+    let matrix = Matrix::from([[2., 1.], [1., 3.]]);
+    let b = Vector::from([3., 5.]);
+    let solution = matrix.solve(&b);
+    assert!(solution.is_ok());
+    assert_eq!(solution.unwrap().all(), &vec![0.8, 1.4]);
+}
+
+#[test]
+fn matrix_solve_example_2() {
+    let matrix = Matrix::from([[0., 1., 0.], [1., 0., 0.], [0., 0., 4.]]);
+    let b = Vector::from([2., 3., 8.]);
+    let solution = matrix.solve(&b);
+    assert!(solution.is_ok());
+    assert_eq!(solution.unwrap().all(), &vec![3., 2., 2.]);
+}
+
+#[test]
+fn matrix_solve_singular_matrix() {
+    let matrix = Matrix::from([[1., 2.], [2., 4.]]);
+    let b = Vector::from([1., 2.]);
+    assert!(matrix.solve(&b).is_err());
+}
+
+#[test]
+fn matrix_solve_invalid() {
+    let matrix = Matrix::from([[1., 2., 3.], [2., 4., 5.]]);
+    assert!(matrix.solve(&Vector::from([1., 2.])).is_err());
+    let matrix = Matrix::from([[1., 2.], [3., 4.]]);
+    assert!(matrix.solve(&Vector::from([1., 2., 3.])).is_err());
+}
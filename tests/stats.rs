@@ -0,0 +1,116 @@
+use matrix::{matrix::Matrix, stats, vector::Vector};
+
+fn assert_close(result: &Matrix, expected: &Matrix) {
+    assert_eq!(result.shape(), expected.shape());
+    for (a, b) in result
+        .iter_rows()
+        .flatten()
+        .zip(expected.iter_rows().flatten())
+    {
+        assert!(
+            (a - b).abs() < 1e-10,
+            "{}\nis not close to\n{}",
+            result,
+            expected
+        );
+    }
+}
+
+fn assert_close_vector(result: &Vector, expected: &Vector) {
+    assert_close(&result.reshape(), &expected.reshape());
+}
+
+#[test]
+fn center_standardize() {
+    let data = Matrix::from([[1., 10.], [2., 30.], [3., 20.]]);
+    assert_eq!(
+        stats::center(&data).all(),
+        &vec![vec![-1., -10.], vec![0., 10.], vec![1., 0.]]
+    );
+    assert_close(
+        &stats::standardize(&data).unwrap(),
+        &Matrix::from([[-1., -1.], [0., 1.], [1., 0.]]),
+    );
+    assert!(stats::standardize(&Matrix::from([[1., 2.], [1., 3.]])).is_err());
+    assert!(stats::standardize(&Matrix::from([[1., 2.]])).is_err());
+}
+
+#[test]
+fn covariance() {
+    let data = Matrix::from([[1., 10., 5.], [2., 30., 5.], [3., 20., 5.]]);
+    assert_close(
+        &stats::covariance(&data).unwrap(),
+        &Matrix::from([[1., 5., 0.], [5., 100., 0.], [0., 0., 0.]]),
+    );
+    assert!(stats::covariance(&Matrix::from([[1., 2.]])).is_err());
+}
+
+#[test]
+fn correlation() {
+    let data = Matrix::from([[1., 2., 3.], [2., 4., 1.], [3., 6., 2.]]);
+    assert_close(
+        &stats::correlation(&data).unwrap(),
+        &Matrix::from([[1., 1., -0.5], [1., 1., -0.5], [-0.5, -0.5, 1.]]),
+    );
+    // Constant feature
+    assert!(stats::correlation(&Matrix::from([[1., 2.], [2., 2.]])).is_err());
+}
+
+#[test]
+fn pca_line() {
+    // Observations on the line y = 2x
+    let data = Matrix::from([[0., 0.], [1., 2.], [2., 4.], [3., 6.]]);
+    let pca = stats::pca(&data, 1).unwrap();
+    let norm = 5_f64.sqrt();
+    assert_close(&pca.components, &Matrix::from([[1. / norm, 2. / norm]]));
+    assert_close_vector(&pca.explained_variance, &Vector::from([25. / 3.]));
+    assert_close_vector(&pca.explained_variance_ratio, &Vector::from([1.]));
+    assert_close_vector(&pca.mean, &Vector::from([1.5, 3.]));
+    let offsets = [-1.5, -0.5, 0.5, 1.5].map(|x| [x * norm]);
+    assert_close(&pca.projected, &Matrix::from(offsets));
+    assert_close(
+        &pca.transform(&Matrix::from([[1.5, 3.]])).unwrap(),
+        &Matrix::from([[0.]]),
+    );
+    assert!(pca.transform(&Matrix::from([[1.]])).is_err());
+}
+
+#[test]
+fn pca_components() {
+    let data = Matrix::from([
+        [2.5, 2.4, 0.5],
+        [0.5, 0.7, 1.5],
+        [2.2, 2.9, 0.1],
+        [1.9, 2.2, 0.9],
+        [3.1, 3.0, 0.3],
+        [2.3, 2.7, 1.2],
+        [2., 1.6, 0.8],
+        [1., 1.1, 1.9],
+    ]);
+    let pca = stats::pca(&data, 3).unwrap();
+    // Orthonormal components
+    assert_close(
+        &pca.components.mul_mat(&pca.components.transpose()),
+        &Matrix::identity(3, 1.),
+    );
+    // Decreasing variances, which sum to the total variance
+    let variance = pca.explained_variance.all();
+    assert!(variance[0] >= variance[1] && variance[1] >= variance[2]);
+    assert!((pca.explained_variance_ratio.sum() - 1.).abs() < 1e-12);
+    assert!(
+        (pca.explained_variance.sum() - stats::covariance(&data).unwrap().trace()).abs() < 1e-12
+    );
+    // The projected data is uncorrelated, with the explained variances
+    let mut expected = Matrix::new([3, 3]);
+    for component in 0..3 {
+        expected[component][component] = variance[component];
+    }
+    assert_close(&stats::covariance(&pca.projected).unwrap(), &expected);
+
+    assert_eq!(stats::pca(&data, 2).unwrap().projected.shape(), [8, 2]);
+    assert!(stats::pca(&data, 0).is_err());
+    assert!(stats::pca(&data, 4).is_err());
+    assert!(stats::pca(&Matrix::from([[1., 1.], [1., 1.]]), 1).is_err());
+    let error = stats::pca(&Matrix::from([[1., f64::NAN], [2., 3.]]), 1).unwrap_err();
+    assert_eq!(error, "PCA expects finite values");
+}
@@ -0,0 +1,102 @@
+use matrix::export::ExportOptions;
+use matrix::matrix::Matrix;
+use matrix::rational::Rational;
+use matrix::trace::{steps_to_latex, steps_to_text, Operation};
+
+#[test]
+fn row_echelon_steps() {
+    let matrix = Matrix::from([[0., 2.], [1., 3.]]);
+    let (result, steps) = matrix.row_echelon_steps();
+    assert_eq!(result.all(), matrix.row_echelon().all());
+    let operations: Vec<Operation> = steps.iter().map(|step| step.operation).collect();
+    assert_eq!(
+        operations,
+        vec![
+            Operation::Swap(1, 0),
+            Operation::Scale(1, 0.5),
+            Operation::AddMultiple(0, 1, -3.),
+        ]
+    );
+    assert_eq!(steps[0].matrix.all(), &vec![vec![1., 3.], vec![0., 2.]]);
+    assert_eq!(steps[2].matrix.all(), &vec![vec![1., 0.], vec![0., 1.]]);
+}
+
+#[test]
+fn inverse_steps_augmented() {
+    let matrix = Matrix::from([[2., 0.], [0., 4.]]);
+    let (inverse, steps) = matrix.inverse_steps().unwrap();
+    assert_eq!(inverse.all(), matrix.inverse().unwrap().all());
+    assert_eq!(steps.len(), 2);
+    assert_eq!(
+        steps[1].matrix.all(),
+        &vec![vec![1., 0., 0.5, 0.], vec![0., 1., 0., 0.25]]
+    );
+    assert!(Matrix::from([[1., 2.], [2., 4.]]).inverse_steps().is_err());
+}
+
+#[test]
+fn determinant_steps() {
+    let matrix = Matrix::from([[2., 0., 2.], [0., 0., 1.], [1., 2., 3.]]);
+    let (determinant, steps) = matrix.determinant_steps();
+    assert_eq!(determinant, matrix.determinant());
+    assert_eq!(determinant, -4.);
+    assert_eq!(
+        steps[0].operation,
+        Operation::FractionFree {
+            target: 1,
+            pivot: 0,
+            scale: 2.,
+            factor: 0.,
+            divisor: 1.
+        }
+    );
+    assert_eq!(steps[2].operation, Operation::Swap(2, 1));
+}
+
+#[test]
+fn rank_steps() {
+    let matrix = Matrix::from([[1., 2.], [2., 4.], [3., 1.]]);
+    let (rank, steps) = matrix.rank_steps();
+    assert_eq!(rank, 2);
+    assert_eq!(steps[0].operation, Operation::Swap(2, 0));
+}
+
+#[test]
+fn steps_rendering() {
+    let matrix = Matrix::from([[2., 4.], [1., 3.]]);
+    let (_, steps) = matrix.row_echelon_steps();
+    assert_eq!(steps[0].operation.to_string(), "R1 ← 0.5·R1");
+    assert_eq!(steps[1].operation.to_string(), "R2 ← R2 - R1");
+    let text = steps_to_text(&steps);
+    assert!(text.starts_with("R1 ← 0.5·R1\n┌      ┐\n│ 1  2 │\n│ 1  3 │\n└      ┘\n\nR2 ← R2 - R1"));
+
+    let options = ExportOptions {
+        fractions: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        steps[0].operation.to_latex(&options),
+        "R_{1} \\leftarrow \\frac{1}{2}R_{1}"
+    );
+    let latex = steps_to_latex(&matrix, &steps, &options);
+    assert!(latex.starts_with("\\begin{align*}\n& \\begin{bmatrix}"));
+    assert!(latex.contains("& \\xrightarrow{R_{2} \\leftarrow R_{2} - R_{1}} \\begin{bmatrix}"));
+}
+
+#[test]
+fn steps_rendering_rational() {
+    let matrix = Matrix::from([
+        [Rational::from(2), Rational::from(4)],
+        [Rational::from(1), Rational::from(3)],
+    ]);
+    let (_, steps) = matrix.row_echelon_steps();
+    let options = ExportOptions::default();
+    assert_eq!(
+        steps[0].operation.to_latex(&options),
+        "R_{1} \\leftarrow \\frac{1}{2}R_{1}"
+    );
+    let latex = steps_to_latex(&matrix, &steps, &options);
+    assert!(latex
+        .starts_with("\\begin{align*}\n& \\begin{bmatrix}\n  2 & 4 \\\\\n  1 & 3\n\\end{bmatrix}"));
+    assert!(latex.contains("& \\xrightarrow{R_{2} \\leftarrow R_{2} - R_{1}} \\begin{bmatrix}"));
+}